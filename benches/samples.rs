@@ -16,6 +16,7 @@ pub fn ceiling_division_by_cond(dividend: usize, divisor: usize) -> usize {
 }
 
 #[inline]
+#[allow(clippy::manual_is_multiple_of)]
 pub fn ceiling_division_by_mod(dividend: usize, divisor: usize) -> usize {
 	let is_rem = dividend % divisor > 0;
 	dividend / divisor + is_rem as usize
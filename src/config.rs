@@ -0,0 +1,270 @@
+use crate::{
+	active::Active,
+	strategy::{Distance, Integrator, LeadingEdge, NumericType, Shifter},
+	Status,
+};
+use core::{convert::TryFrom, fmt, time::Duration};
+
+/// # Invalid Timing Configuration
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ConfigError {
+	/// The sample rate is 0 Hz
+	ZeroSampleRate,
+	/// A hold time is zero
+	ZeroHoldTime,
+	/// A hold time needs more samples than the strategy can count
+	TooManySamples(u64),
+}
+
+impl ConfigError {
+	const fn message(&self) -> &'static str {
+		match self {
+			ConfigError::ZeroSampleRate => "the sample rate is zero",
+			ConfigError::ZeroHoldTime => "a hold time is zero",
+			ConfigError::TooManySamples(_) => "a hold time needs too many samples",
+		}
+	}
+}
+
+impl fmt::Display for ConfigError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		f.write_str(self.message())?;
+		if let ConfigError::TooManySamples(n) = self {
+			write!(f, " ({})", n)?;
+		}
+		Ok(())
+	}
+}
+
+/// # Timing of a Debounced Input
+/// Computes the number of samples needed to hold a press (settling on the
+/// [active](trait@Active) value) and a release at a sample rate, and creates
+/// strategies which wait for them.
+///
+/// All arithmetic is checked. Every function which does not create a strategy
+/// can be used in a `const` context, where [`Self::validate`] turns an invalid
+/// configuration into a compile error:
+///
+/// ```
+/// use bounced::DebounceConfig;
+/// const BUTTON: DebounceConfig = DebounceConfig::new(1000).press_ms(5).release_ms(20).validate();
+/// ```
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct DebounceConfig {
+	sample_freq: u32,
+	press: Duration,
+	release: Duration,
+}
+
+impl DebounceConfig {
+	/// Create a new configuration sampling at `sample_freq` (in Hz)
+	///
+	/// Both hold times start at zero, which is invalid.
+	pub const fn new(sample_freq: u32) -> Self {
+		Self {
+			sample_freq,
+			press: Duration::from_millis(0),
+			release: Duration::from_millis(0),
+		}
+	}
+
+	/// Sets both hold times
+	pub const fn hold(self, hold: Duration) -> Self {
+		self.press(hold).release(hold)
+	}
+
+	/// Sets the time a press needs to be held
+	pub const fn press(self, press: Duration) -> Self {
+		Self { press, ..self }
+	}
+
+	/// Sets the time a release needs to be held
+	pub const fn release(self, release: Duration) -> Self {
+		Self { release, ..self }
+	}
+
+	/// Sets both hold times (in ms)
+	pub const fn hold_ms(self, hold_time_ms: u64) -> Self {
+		self.hold(Duration::from_millis(hold_time_ms))
+	}
+
+	/// Sets the time a press needs to be held (in ms)
+	pub const fn press_ms(self, hold_time_ms: u64) -> Self {
+		self.press(Duration::from_millis(hold_time_ms))
+	}
+
+	/// Sets the time a release needs to be held (in ms)
+	pub const fn release_ms(self, hold_time_ms: u64) -> Self {
+		self.release(Duration::from_millis(hold_time_ms))
+	}
+
+	/// Computes the samples which occur while holding for `hold`. If a partial
+	/// sample will have occurred, rounds up.
+	const fn samples(&self, hold: Duration) -> Result<u64, ConfigError> {
+		if self.sample_freq == 0 {
+			return Err(ConfigError::ZeroSampleRate);
+		}
+		// cannot overflow: at most u32::MAX * u64::MAX * 1e9 < u128::MAX
+		let samples_in_1g_secs = self.sample_freq as u128 * hold.as_nanos();
+		if samples_in_1g_secs == 0 {
+			return Err(ConfigError::ZeroHoldTime);
+		}
+		// ceiling division
+		let samples = 1 + (samples_in_1g_secs - 1) / 1_000_000_000;
+		if samples > u64::MAX as u128 {
+			Err(ConfigError::TooManySamples(u64::MAX))
+		} else {
+			Ok(samples as u64)
+		}
+	}
+
+	/// The number of samples a press needs to be held
+	pub const fn press_samples(&self) -> Result<u64, ConfigError> {
+		self.samples(self.press)
+	}
+
+	/// The number of samples a release needs to be held
+	pub const fn release_samples(&self) -> Result<u64, ConfigError> {
+		self.samples(self.release)
+	}
+
+	/// The number of samples either a press or release needs to be held
+	pub const fn max_samples(&self) -> Result<u64, ConfigError> {
+		match (self.press_samples(), self.release_samples()) {
+			(Ok(press), Ok(release)) => Ok(if press > release { press } else { release }),
+			(Err(e), _) | (_, Err(e)) => Err(e),
+		}
+	}
+
+	/// Checks the configuration, panicking if it is invalid. In a `const`
+	/// context, this is a compile error.
+	pub const fn validate(self) -> Self {
+		if let Err(e) = self.max_samples() {
+			panic!("{}", e.message());
+		}
+		self
+	}
+
+	/// Create an [`Integrator`] which holds both presses and releases for the
+	/// longer of the two hold times
+	pub fn integrator<A: Active, D: Distance>(&self) -> Result<Integrator<D>, ConfigError> {
+		let samples = self.max_samples()?;
+		usize::try_from(samples)
+			.ok()
+			.and_then(D::from_samples)
+			.map(Integrator::new::<A>)
+			.ok_or(ConfigError::TooManySamples(samples))
+	}
+
+	/// Create a [`Shifter`] which holds both presses and releases for at least
+	/// the longer of the two hold times. The number of samples is set by the
+	/// type `T`.
+	pub fn shifter<A: Active, T: NumericType>(&self) -> Result<Shifter<T>, ConfigError> {
+		let samples = self.max_samples()?;
+		if T::MAX.steps() < samples {
+			Err(ConfigError::TooManySamples(samples))
+		} else {
+			Ok(Shifter::new::<A>())
+		}
+	}
+
+	/// Create a [`LeadingEdge`] which ignores the input for the press hold
+	/// time after a press, and for the release hold time after a release
+	pub fn leading_edge<A: Active>(&self) -> Result<LeadingEdge, ConfigError> {
+		let fits =
+			|samples| u8::try_from(samples).map_err(|_| ConfigError::TooManySamples(samples));
+		let press = fits(self.press_samples()?)?;
+		let release = fits(self.release_samples()?)?;
+		Ok(if A::ACTIVE_VALUE == Status::High {
+			LeadingEdge::with_lockouts::<A>(press, release)
+		} else {
+			LeadingEdge::with_lockouts::<A>(release, press)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::{Integrand, Integrand32, Strategy},
+	};
+	use core::num::{NonZeroU16, NonZeroU8};
+
+	const BUTTON: DebounceConfig = DebounceConfig::new(1000)
+		.press_ms(5)
+		.release_ms(20)
+		.validate();
+
+	#[test]
+	fn samples() {
+		assert_eq!(BUTTON.press_samples(), Ok(5));
+		assert_eq!(BUTTON.release_samples(), Ok(20));
+		assert_eq!(BUTTON.max_samples(), Ok(20));
+
+		let c = DebounceConfig::new(250).hold(Duration::from_micros(4_001));
+		assert_eq!(c.press_samples(), Ok(2));
+		let c = DebounceConfig::new(1_000_000).hold(Duration::from_nanos(1_500));
+		assert_eq!(c.press_samples(), Ok(2));
+		let c = DebounceConfig::new(4_000_000).hold(Duration::from_nanos(500));
+		assert_eq!(c.press_samples(), Ok(2));
+		let c = DebounceConfig::new(1000).hold(Duration::from_nanos(1));
+		assert_eq!(c.press_samples(), Ok(1));
+		let c = DebounceConfig::new(u32::MAX).hold(Duration::from_secs(u64::MAX));
+		assert_eq!(
+			c.press_samples(),
+			Err(ConfigError::TooManySamples(u64::MAX))
+		);
+	}
+
+	#[test]
+	fn errors() {
+		assert_eq!(
+			DebounceConfig::new(0).hold_ms(5).max_samples(),
+			Err(ConfigError::ZeroSampleRate)
+		);
+		assert_eq!(
+			DebounceConfig::new(1000).press_ms(5).max_samples(),
+			Err(ConfigError::ZeroHoldTime)
+		);
+		assert_eq!(
+			BUTTON.hold_ms(300).integrator::<Low, NonZeroU8>().err(),
+			Some(ConfigError::TooManySamples(300))
+		);
+		assert_eq!(
+			BUTTON.shifter::<Low, u8>().err(),
+			Some(ConfigError::TooManySamples(20))
+		);
+	}
+
+	#[test]
+	#[should_panic]
+	fn invalid() {
+		DebounceConfig::new(1000).validate();
+	}
+
+	#[test]
+	fn strategies() {
+		let i = BUTTON.integrator::<High, NonZeroU16>().unwrap();
+		for _ in 0..19 {
+			assert_eq!(i.update(Status::High), None);
+		}
+		assert_eq!(i.update(Status::High), Some(Status::High));
+
+		assert!(BUTTON.shifter::<Low, u32>().is_ok());
+		assert!(BUTTON.shifter::<Low, Integrand<20>>().is_ok());
+		assert!(BUTTON.shifter::<Low, Integrand<19>>().is_err());
+		let long = DebounceConfig::new(1_000_000).hold_ms(3_000_000);
+		assert!(long.shifter::<Low, Integrand32<{ u32::MAX }>>().is_ok());
+		assert!(long.shifter::<Low, u64>().is_err());
+
+		let e = BUTTON.leading_edge::<Low>().unwrap();
+		assert_eq!(e.update(Status::Low), Some(Status::Low));
+		assert_eq!(e.remaining(), 5);
+		let e = BUTTON.leading_edge::<High>().unwrap();
+		assert_eq!(e.update(Status::High), Some(Status::High));
+		assert_eq!(e.remaining(), 5);
+		assert_eq!(e.update(Status::Low), Some(Status::High));
+	}
+}
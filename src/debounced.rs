@@ -1,13 +1,14 @@
 use crate::{
 	active::Active,
+	query::queries,
 	strategy::{self, Strategy},
-	Status,
+	InputSource, Status,
 };
 use core::{cell::Cell, marker::PhantomData, num::NonZeroU8};
 
 /// # Debounces Input
-/// For any signal, uses the `strategy` to determine if the bit has settled.
-/// This is typically used for buttons.
+/// For any signal, uses the `strategy` to determine if the bit read from the
+/// [`InputSource`] has settled. This is typically used for buttons.
 ///
 /// Maintains storage of the last settled value as determined by the
 /// [`Strategy`]. The first stored value will be
@@ -16,15 +17,23 @@ use core::{cell::Cell, marker::PhantomData, num::NonZeroU8};
 /// Most implementations of debouncing return the last stored value, which
 /// corresponds to the [`Self::get_latest`] and [`Self::is_triggered_latest`]
 /// functions.
+///
+/// Every built-in strategy and Debounced input can be created in a `const`
+/// context, so tables of inputs using `fn` pointers (see [`DebouncedFn`]) can
+/// be `const` items. Since the state is stored in [`Cell`]s, a `static` input
+/// needs a wrapper which is [`Sync`] (eg. a critical-section mutex).
 pub struct Debounced<A, S, F> {
-	is_input_high: F,
+	input: F,
 	strategy: S,
 	hysteresis: Cell<Status>,
-	_a: PhantomData<*const A>, // No A is owned or referenced
+	_a: PhantomData<fn() -> A>, // No A is owned or referenced
 }
 
+/// Convenience for [`Debounced`] inputs read by a `fn() -> bool`
+pub type DebouncedFn<A, S> = Debounced<A, S, fn() -> bool>;
+
 /// Convenience for [`Debounced<_, Integrator, _>`]
-pub type DebouncedIntegrator<A, F> = Debounced<A, strategy::Integrator, F>;
+pub type DebouncedIntegrator<A, F, D = NonZeroU8> = Debounced<A, strategy::Integrator<D>, F>;
 /// Convenience for [`Debounced<_, Shifter, _>`]
 pub type DebouncedGenericShift<A, T, F> = Debounced<A, strategy::Shifter<T>, F>;
 /// Convenience for [`Debounced<_, IntegrandShifter<_>, _>`]
@@ -34,99 +43,100 @@ pub type DebouncedIntegrandShift<A, F, const N: u8> =
 impl<A, S, F> Debounced<A, S, F>
 where
 	A: Active,
-	F: Fn() -> bool,
 {
 	/// Creates a new Debounced input using any [`Strategy`]
-	pub fn new(strategy: S, is_input_high: F) -> Self {
+	pub const fn new(strategy: S, input: F) -> Self {
 		Self {
-			is_input_high,
+			input,
 			strategy,
-			hysteresis: Cell::new(!A::ACTIVE_VALUE),
+			hysteresis: Cell::new(A::ACTIVE_VALUE.invert()),
 			_a: PhantomData,
 		}
 	}
 }
 
-impl<A, F> DebouncedIntegrator<A, F>
+impl<A, F, D> DebouncedIntegrator<A, F, D>
 where
 	A: Active,
-	F: Fn() -> bool,
+	F: InputSource,
+	D: strategy::Distance,
 {
 	/// [Convenience](strategy::Integrator::new) to create a new
 	/// integrator-debounced input
-	pub fn with_integrator(max: NonZeroU8, is_input_high: F) -> Self {
-		Self::new(strategy::Integrator::new::<A>(max), is_input_high)
+	pub const fn with_integrator(max: D, input: F) -> Self {
+		Self::new(strategy::Integrator::new::<A>(max), input)
 	}
 }
 
 impl<A, F> DebouncedGenericShift<A, u8, F>
 where
 	A: Active,
-	F: Fn() -> bool,
+	F: InputSource,
 {
 	/// [Convenience](strategy::Shifter::new) to create a new
 	/// shift-debounced input
-	pub fn with_shifter<T>(is_input_high: F) -> DebouncedGenericShift<A, T, F>
+	pub const fn with_shifter<T>(input: F) -> DebouncedGenericShift<A, T, F>
 	where
 		T: strategy::NumericType,
 	{
-		Debounced::new(strategy::Shifter::new::<A>(), is_input_high)
+		Debounced::new(strategy::Shifter::new::<A>(), input)
 	}
 }
 
-impl<A, F, const N: u8> DebouncedIntegrandShift<A, F, N>
+impl<A, F, T> DebouncedGenericShift<A, T, F>
 where
 	A: Active,
-	F: Fn() -> bool,
+	F: InputSource,
+	T: strategy::NumericType,
 {
-	/// Create a new integration-debounced input with no size-overhead
-	pub fn with(is_input_high: F) -> Self {
-		Self::new(strategy::Shifter::new::<A>(), is_input_high)
+	/// Create a new shift-debounced input (eg. an integration-debounced input
+	/// with no size-overhead)
+	pub const fn with(input: F) -> Self {
+		Self::new(strategy::Shifter::new::<A>(), input)
 	}
 }
 
 impl<A, S, F> Debounced<A, S, F>
 where
 	S: Strategy,
-	F: Fn() -> bool,
 {
-	#[inline]
-	fn input_status(&self) -> Status {
-		if (self.is_input_high)() {
-			Status::High
-		} else {
-			Status::Low
-		}
-	}
-
-	/// If the `strategy` has not settled on a [`Status`], will not pick one.
-	///
-	/// If you just want to update the status of an input (eg. in a timer
-	/// interrupt), use this function.
-	pub fn try_get(&self) -> Option<Status> {
-		let s = self.strategy.update(self.input_status());
+	/// Updates the `strategy` with a sample read elsewhere
+	pub(crate) fn update_with(&self, status: Status) -> Option<Status> {
+		let s = self.strategy.update(status);
 		if let Some(s) = s {
 			self.hysteresis.set(s);
 		}
 		s
 	}
 
-	/// If the `strategy` has not settled on a [`Status`], uses the last settled
-	/// value.
-	pub fn get_latest(&self) -> Status {
-		self.try_get().unwrap_or_else(|| self.hysteresis.get())
+	/// The input which is sampled
+	pub(crate) fn input(&self) -> &F {
+		&self.input
 	}
 
-	/// Blocks until the `strategy` has settled on a [`Status`] using tight
-	/// polling.
-	pub fn get_blocking(&self) -> Status {
-		let mut status;
-		loop {
-			status = self.try_get();
-			if let Some(s) = status {
-				return s;
-			}
-		}
+	/// The last settled value, without updating the `strategy`.
+	pub fn last_settled(&self) -> Status {
+		self.hysteresis.get()
+	}
+
+	/// How far the `strategy` is from settling, without updating it (eg. for
+	/// a "hold to confirm" progress bar)
+	pub fn progress(&self) -> strategy::Progress {
+		self.strategy.progress(self.hysteresis.get())
+	}
+}
+
+impl<A, S, F> Debounced<A, S, F>
+where
+	S: Strategy,
+	F: InputSource,
+{
+	/// If the `strategy` has not settled on a [`Status`], will not pick one.
+	///
+	/// If you just want to update the status of an input (eg. in a timer
+	/// interrupt), use this function.
+	pub fn try_get(&self) -> Option<Status> {
+		self.update_with(self.input.read())
 	}
 }
 
@@ -134,50 +144,29 @@ impl<A, S, F> Debounced<A, S, F>
 where
 	A: Active,
 	S: Strategy,
-	F: Fn() -> bool,
+	F: InputSource,
 {
 	/// Create a Debounced input using the strategy's current status
-	pub fn with_current_strategy(strategy: S, is_input_high: F) -> Option<Self> {
+	pub fn with_current_strategy(strategy: S, input: F) -> Option<Self> {
 		let status = strategy.status()?;
 		Some(Self {
-			is_input_high,
+			input,
 			strategy,
 			hysteresis: Cell::new(status),
 			_a: PhantomData,
 		})
 	}
 
-	/// If the `strategy` has not settled on a [`Status`], uses the
-	/// [inactive](trait@Active) value.
-	pub fn get_or_unset(&self) -> Status {
-		self.try_get().unwrap_or(!A::ACTIVE_VALUE)
-	}
-
-	/// Compares [`Self::try_get`] with the value of an active input
-	pub fn try_is_triggered(&self) -> Option<bool> {
-		self.try_get().map(|s| s == A::ACTIVE_VALUE)
-	}
-
-	/// Compares [`Self::get_latest`] with the value of an active input
-	pub fn is_triggered_latest(&self) -> bool {
-		self.get_latest() == A::ACTIVE_VALUE
-	}
-
-	/// Compares [`Self::get_blocking`] with the value of an active input
-	pub fn is_triggered_blocking(&self) -> bool {
-		self.get_blocking() == A::ACTIVE_VALUE
-	}
-
-	/// Compares [`Self::get_or_unset`] with the value of an active input
-	pub fn is_triggered_or_unset(&self) -> bool {
-		self.get_or_unset() == A::ACTIVE_VALUE
-	}
+	queries!(A);
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
+	extern crate std;
 	use super::*;
 	use crate::active::{High, Low};
+	use std::sync::Mutex;
 	type DbInt<F> = DebouncedIntegrator<Low, F>;
 	type DbShf<T, F> = DebouncedGenericShift<High, T, F>;
 
@@ -246,6 +235,17 @@ mod tests {
 		assert_eq!(d.try_get(), Some(Status::High));
 	}
 
+	#[test]
+	fn progress() {
+		use strategy::Progress;
+		let d = DbInt::with_integrator(NonZeroU8::new(6).unwrap(), || false);
+		assert_eq!(d.progress(), Progress::new(Status::High, 6, 6));
+		d.try_get();
+		assert_eq!(d.progress(), Progress::new(Status::Low, 1, 6));
+		assert_eq!(d.get_blocking(), Status::Low);
+		assert!(d.progress().is_done());
+	}
+
 	#[test]
 	fn high_is_triggered() {
 		let d = DbShf::with_shifter::<u8>(|| true);
@@ -260,4 +260,26 @@ mod tests {
 		assert_eq!(d.is_triggered_or_unset(), true);
 		assert_eq!(d.try_is_triggered(), Some(true));
 	}
+
+	fn released() -> bool {
+		true
+	}
+
+	fn pressed() -> bool {
+		false
+	}
+
+	static TABLE: Mutex<[DebouncedFn<Low, strategy::IntegrandShifter<3>>; 2]> =
+		Mutex::new([Debounced::with(released), Debounced::with(pressed)]);
+	static INTEGRATED: Mutex<DebouncedFn<Low, strategy::Integrator>> = Mutex::new(
+		Debounced::with_integrator(NonZeroU8::new(6).unwrap(), pressed),
+	);
+
+	#[test]
+	fn statics() {
+		let [a, b] = &*TABLE.lock().unwrap();
+		assert_eq!(a.is_triggered_blocking(), false);
+		assert_eq!(b.is_triggered_blocking(), true);
+		assert_eq!(INTEGRATED.lock().unwrap().is_triggered_blocking(), true);
+	}
 }
@@ -0,0 +1,258 @@
+use crate::{strategy::Strategy, Debounced, InputSource, Status};
+#[cfg(feature = "futures")]
+use core::{
+	future::Future,
+	pin::Pin,
+	task::{Context, Poll},
+};
+#[cfg(feature = "futures")]
+use std::boxed::Box;
+use std::{
+	thread,
+	time::{Duration, Instant},
+};
+
+/// # A Debounced Edge
+/// The value an input settled on, and when.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Event {
+	/// The newly settled value
+	pub status: Status,
+	/// When the sample which settled the input was taken
+	pub at: Instant,
+}
+
+/// # Events of a Debounced Input
+/// Samples a [`Debounced`] input once per `period`, and yields an [`Event`]
+/// each time it settles on a different value. Created with
+/// [`Debounced::events`].
+///
+/// As an [`Iterator`], the current thread sleeps between samples, and it never
+/// ends. With the `futures` feature, [`Self::stream`] waits for samples with
+/// the timer of any executor instead.
+pub struct Events<'a, A, S, F> {
+	debounced: &'a Debounced<A, S, F>,
+	period: Duration,
+	next: Instant,
+	last: Status,
+}
+
+impl<A, S, F> Debounced<A, S, F>
+where
+	S: Strategy,
+	F: InputSource,
+{
+	/// The [`Events`] of the input when sampled every `period`, starting now.
+	/// The first event is a change from the last settled value.
+	pub fn events(&self, period: Duration) -> Events<'_, A, S, F> {
+		Events {
+			debounced: self,
+			period,
+			next: Instant::now(),
+			last: self.last_settled(),
+		}
+	}
+}
+
+impl<A, S, F> Events<'_, A, S, F>
+where
+	S: Strategy,
+	F: InputSource,
+{
+	/// Samples the input which is due, returning an event if it settled on a
+	/// different value
+	fn sample(&mut self, now: Instant) -> Option<Event> {
+		self.next = (self.next + self.period).max(now);
+		match self.debounced.try_get() {
+			Some(status) if status != self.last => {
+				self.last = status;
+				Some(Event { status, at: now })
+			}
+			_ => None,
+		}
+	}
+}
+
+impl<A, S, F> Iterator for Events<'_, A, S, F>
+where
+	S: Strategy,
+	F: InputSource,
+{
+	type Item = Event;
+
+	fn next(&mut self) -> Option<Event> {
+		loop {
+			let now = Instant::now();
+			if now < self.next {
+				thread::sleep(self.next - now);
+				continue;
+			}
+			if let Some(event) = self.sample(now) {
+				return Some(event);
+			}
+		}
+	}
+}
+
+/// # A Stream of Events
+/// Samples a [`Debounced`] input like [`Events`], but waits for each sample
+/// with a future from a timer instead of sleeping. Created with
+/// [`Events::stream`].
+#[cfg(feature = "futures")]
+pub struct EventStream<'a, A, S, F, T, Fut> {
+	events: Events<'a, A, S, F>,
+	timer: T,
+	sleep: Option<Pin<Box<Fut>>>,
+}
+
+#[cfg(feature = "futures")]
+impl<'a, A, S, F> Events<'a, A, S, F> {
+	/// A [`Stream`](futures_core::Stream) of the events, where `timer` returns
+	/// a future which completes at the given [`Instant`] (eg. a `sleep_until`
+	/// of the executor). Between samples, the stream is only woken by the
+	/// timer.
+	pub fn stream<T, Fut>(self, timer: T) -> EventStream<'a, A, S, F, T, Fut>
+	where
+		T: FnMut(Instant) -> Fut,
+		Fut: Future<Output = ()>,
+	{
+		EventStream {
+			events: self,
+			timer,
+			sleep: None,
+		}
+	}
+}
+
+#[cfg(feature = "futures")]
+impl<A, S, F, T, Fut> futures_core::Stream for EventStream<'_, A, S, F, T, Fut>
+where
+	S: Strategy,
+	F: InputSource,
+	T: FnMut(Instant) -> Fut + Unpin,
+	Fut: Future<Output = ()>,
+{
+	type Item = Event;
+
+	fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Event>> {
+		let this = self.get_mut();
+		loop {
+			if let Some(sleep) = &mut this.sleep {
+				if sleep.as_mut().poll(cx).is_pending() {
+					return Poll::Pending;
+				}
+				this.sleep = None;
+			}
+			let now = Instant::now();
+			if now < this.events.next {
+				this.sleep = Some(Box::pin((this.timer)(this.events.next)));
+				continue;
+			}
+			if let Some(event) = this.events.sample(now) {
+				return Poll::Ready(Some(event));
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, DebouncedIntegrator};
+	use core::{cell::Cell, num::NonZeroU8};
+
+	#[test]
+	fn iterator() {
+		let samples = Cell::new(0);
+		// pressed (low) for samples 4 to 9
+		let input = || {
+			let n = samples.get();
+			samples.set(n + 1);
+			!(4..10).contains(&n)
+		};
+		let d = DebouncedIntegrator::<Low, _>::with_integrator(NonZeroU8::new(2).unwrap(), input);
+		let start = Instant::now();
+		let mut events = d.events(Duration::from_millis(1));
+		let pressed = events.next().unwrap();
+		assert_eq!(pressed.status, Status::Low);
+		assert_eq!(samples.get(), 6);
+		let released = events.next().unwrap();
+		assert_eq!(released.status, Status::High);
+		assert_eq!(samples.get(), 12);
+		assert!(released.at - start >= Duration::from_millis(11));
+		assert!(pressed.at < released.at);
+	}
+
+	#[cfg(feature = "futures")]
+	#[test]
+	fn stream() {
+		use futures_core::Stream;
+		use std::{
+			sync::{
+				atomic::{AtomicUsize, Ordering},
+				Arc,
+			},
+			task::{Wake, Waker},
+			thread::Thread,
+		};
+
+		struct Unpark(Thread, AtomicUsize);
+
+		impl Wake for Unpark {
+			fn wake(self: Arc<Self>) {
+				self.1.fetch_add(1, Ordering::Relaxed);
+				self.0.unpark();
+			}
+		}
+
+		// completes once woken by a thread sleeping until `at`
+		struct Sleep {
+			at: Instant,
+			started: bool,
+		}
+
+		impl Future for Sleep {
+			type Output = ();
+
+			fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+				if Instant::now() >= self.at {
+					return Poll::Ready(());
+				}
+				if !self.started {
+					self.started = true;
+					let (at, waker) = (self.at, cx.waker().clone());
+					thread::spawn(move || {
+						thread::sleep(at.saturating_duration_since(Instant::now()));
+						waker.wake();
+					});
+				}
+				Poll::Pending
+			}
+		}
+
+		let samples = Cell::new(0);
+		// pressed (low) for samples 4 to 9
+		let input = || {
+			let n = samples.get();
+			samples.set(n + 1);
+			!(4..10).contains(&n)
+		};
+		let d = DebouncedIntegrator::<Low, _>::with_integrator(NonZeroU8::new(2).unwrap(), input);
+		let timer = |at| Sleep { at, started: false };
+		let mut events = d.events(Duration::from_millis(1)).stream(timer);
+		let wakes = Arc::new(Unpark(thread::current(), AtomicUsize::new(0)));
+		let waker = Waker::from(wakes.clone());
+		let mut cx = Context::from_waker(&waker);
+		let mut poll = || loop {
+			match Pin::new(&mut events).poll_next(&mut cx) {
+				Poll::Ready(event) => return event.unwrap().status,
+				Poll::Pending => thread::park(),
+			}
+		};
+		assert_eq!(poll(), Status::Low);
+		assert_eq!(poll(), Status::High);
+		assert_eq!(samples.get(), 12);
+		// only woken by the timer, once per sample after the first
+		assert!(wakes.1.load(Ordering::Relaxed) <= 11);
+	}
+}
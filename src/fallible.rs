@@ -0,0 +1,168 @@
+use crate::{
+	active::Active,
+	query::queries,
+	strategy::{Progress, Strategy},
+	Debounced, FallibleInput, Status,
+};
+use core::{cell::Cell, num::NonZeroU8};
+
+/// # Handling of Input Errors
+/// What a [`TryDebounced`] input does when reading its input fails. The
+/// `strategy` is never updated by a failed read.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub enum ErrorPolicy {
+	/// Return every error
+	Propagate,
+	/// Ignore every error, as if the input was unstable (`None`)
+	Hold,
+	/// Ignore errors as if the input was unstable (`None`), until this many
+	/// reads in a row have failed. Then, return errors until a read succeeds.
+	///
+	/// A limit of `1` returns every error, like [`Self::Propagate`].
+	FaultLimit(NonZeroU8),
+}
+
+/// # Debounces Input which can Fail
+/// Like [`Debounced`], but reads a [`FallibleInput`]. Every function returns
+/// the error from the input, unless the [`ErrorPolicy`] ignores it.
+pub struct TryDebounced<A, S, F> {
+	debounced: Debounced<A, S, F>,
+	policy: ErrorPolicy,
+	faults: Cell<u8>,
+}
+
+impl<A, S, F> TryDebounced<A, S, F>
+where
+	A: Active,
+{
+	/// Creates a new TryDebounced input using any [`Strategy`]
+	pub const fn new(strategy: S, input: F, policy: ErrorPolicy) -> Self {
+		Self {
+			debounced: Debounced::new(strategy, input),
+			policy,
+			faults: Cell::new(0),
+		}
+	}
+}
+
+impl<A, S, F> TryDebounced<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: FallibleInput,
+{
+	/// The number of reads in a row which have failed
+	pub fn faults(&self) -> u8 {
+		self.faults.get()
+	}
+
+	/// The last settled value, without updating the `strategy`.
+	pub fn last_settled(&self) -> Status {
+		self.debounced.last_settled()
+	}
+
+	/// How far the `strategy` is from settling, without updating it
+	pub fn progress(&self) -> Progress {
+		self.debounced.progress()
+	}
+
+	/// If the `strategy` has not settled on a [`Status`], will not pick one.
+	///
+	/// If you just want to update the status of an input (eg. in a timer
+	/// interrupt), use this function.
+	pub fn try_get(&self) -> Result<Option<Status>, F::Error> {
+		match self.debounced.input().try_read() {
+			Ok(status) => {
+				self.faults.set(0);
+				Ok(self.debounced.update_with(status))
+			}
+			Err(e) => {
+				let faults = self.faults.get().saturating_add(1);
+				self.faults.set(faults);
+				match self.policy {
+					ErrorPolicy::Propagate => Err(e),
+					ErrorPolicy::Hold => Ok(None),
+					ErrorPolicy::FaultLimit(limit) if faults >= limit.get() => Err(e),
+					ErrorPolicy::FaultLimit(_) => Ok(None),
+				}
+			}
+		}
+	}
+
+	queries!(A, F::Error);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, strategy::Integrator};
+
+	#[derive(Debug, PartialEq)]
+	struct Nack;
+
+	#[test]
+	fn propagate() {
+		let bus = Cell::new(Ok(false));
+		let d = TryDebounced::<Low, _, _>::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			|| bus.get().map_err(|()| Nack),
+			ErrorPolicy::Propagate,
+		);
+		assert_eq!(d.try_get(), Ok(None));
+		bus.set(Err(()));
+		assert_eq!(d.try_get(), Err(Nack));
+		assert_eq!(d.get_latest(), Err(Nack));
+		bus.set(Ok(false));
+		assert_eq!(d.try_is_triggered(), Ok(Some(true)));
+		bus.set(Err(()));
+		assert_eq!(d.is_triggered_blocking(), Err(Nack));
+		assert_eq!(d.last_settled(), Status::Low);
+	}
+
+	#[test]
+	fn hold() {
+		let bus = Cell::new(Ok(false));
+		let d = TryDebounced::<Low, _, _>::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			|| bus.get().map_err(|()| Nack),
+			ErrorPolicy::Hold,
+		);
+		assert_eq!(d.try_get(), Ok(None));
+		bus.set(Err(()));
+		for _ in 0..300 {
+			assert_eq!(d.try_get(), Ok(None));
+		}
+		assert_eq!(d.faults(), u8::MAX);
+		assert_eq!(d.is_triggered_latest(), Ok(false));
+		assert_eq!(d.is_triggered_or_unset(), Ok(false));
+		bus.set(Ok(false));
+		assert_eq!(d.is_triggered_latest(), Ok(true));
+		assert_eq!(d.faults(), 0);
+	}
+
+	#[test]
+	fn fault_limit() {
+		let bus = Cell::new(Err(()));
+		let d = TryDebounced::<Low, _, _>::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			|| bus.get().map_err(|()| Nack),
+			ErrorPolicy::FaultLimit(NonZeroU8::new(3).unwrap()),
+		);
+		assert_eq!(d.try_get(), Ok(None));
+		assert_eq!(d.try_get(), Ok(None));
+		assert_eq!(d.try_get(), Err(Nack));
+		assert_eq!(d.try_get(), Err(Nack));
+		bus.set(Ok(true));
+		assert_eq!(d.get_blocking(), Ok(Status::High));
+		bus.set(Err(()));
+		assert_eq!(d.try_get(), Ok(None));
+		assert_eq!(d.faults(), 1);
+
+		let d = TryDebounced::<Low, _, _>::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			|| bus.get().map_err(|()| Nack),
+			ErrorPolicy::FaultLimit(NonZeroU8::new(1).unwrap()),
+		);
+		assert_eq!(d.try_get(), Err(Nack));
+	}
+}
@@ -0,0 +1,239 @@
+use crate::{
+	active::Active,
+	strategy::{Progress, Strategy},
+	InputSource, Status,
+};
+use core::{array, cell::Cell, marker::PhantomData};
+
+/// # Changes to a Group of Inputs
+/// Bitmasks (bit `i` for input `i`) of the inputs which settled on a different
+/// value since the last poll of a [`DebouncedGroup`].
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Changes {
+	/// Inputs which settled on a different value
+	pub changed: u64,
+	/// Inputs which settled on the [active](trait@Active) value
+	pub activated: u64,
+	/// Inputs which settled on the [inactive](trait@Active) value
+	pub deactivated: u64,
+}
+
+impl Changes {
+	/// If no input changed
+	pub fn is_empty(&self) -> bool {
+		self.changed == 0
+	}
+}
+
+/// # Debounces a Group of Inputs
+/// Holds `N` inputs, each debounced by its own [`Strategy`] created from the
+/// same configuration. All inputs are sampled in one call per tick, either by
+/// reading each [`InputSource`] ([`Self::poll`]) or from one read of a port
+/// ([`Self::update`]).
+///
+/// The settled values are stored as a bitmask, so `N` can be at most 64.
+/// Otherwise, creating the group fails to compile. The first settled values
+/// will be [`!Active::ACTIVE_VALUE`](trait@Active).
+pub struct DebouncedGroup<A, S, F, const N: usize> {
+	inputs: [F; N],
+	strategies: [S; N],
+	settled: Cell<u64>,
+	_a: PhantomData<fn() -> A>, // No A is owned or referenced
+}
+
+impl<A, S, F, const N: usize> DebouncedGroup<A, S, F, N>
+where
+	A: Active,
+{
+	const VALID: () = assert!(N <= 64, "a DebouncedGroup holds at most 64 inputs");
+
+	/// The mask of all inputs
+	const ALL: u64 = if N == 64 { u64::MAX } else { (1 << N) - 1 };
+
+	/// The mask of the inputs which are high when settled on the
+	/// [active](trait@Active) value
+	const ACTIVE: u64 = if matches!(A::ACTIVE_VALUE, Status::High) {
+		Self::ALL
+	} else {
+		0
+	};
+
+	/// Creates a new group of the `inputs`, creating the strategy of each
+	/// input with `strategy`
+	pub fn new(inputs: [F; N], mut strategy: impl FnMut() -> S) -> Self {
+		Self::with_strategies(inputs, array::from_fn(|_| strategy()))
+	}
+
+	/// Creates a new group of the `inputs` debounced by the `strategies`
+	pub const fn with_strategies(inputs: [F; N], strategies: [S; N]) -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::VALID;
+		Self {
+			inputs,
+			strategies,
+			settled: Cell::new(!Self::ACTIVE & Self::ALL),
+			_a: PhantomData,
+		}
+	}
+
+	/// The inputs
+	pub fn inputs(&self) -> &[F; N] {
+		&self.inputs
+	}
+
+	/// The last settled value of input `i`
+	///
+	/// # Panics
+	/// If `i` is not less than `N`
+	pub fn get(&self, i: usize) -> Status {
+		assert!(i < N, "input {} is not in a group of {}", i, N);
+		(self.settled.get() & 1 << i != 0).into()
+	}
+
+	/// Compares [`Self::get`] with the value of an active input
+	///
+	/// # Panics
+	/// If `i` is not less than `N`
+	pub fn is_triggered(&self, i: usize) -> bool {
+		self.get(i) == A::ACTIVE_VALUE
+	}
+
+	/// The mask of the inputs which last settled high
+	pub fn settled(&self) -> u64 {
+		self.settled.get()
+	}
+
+	/// The mask of the inputs which last settled on the
+	/// [active](trait@Active) value
+	pub fn triggered(&self) -> u64 {
+		!(self.settled.get() ^ Self::ACTIVE) & Self::ALL
+	}
+}
+
+impl<A, S, F, const N: usize> DebouncedGroup<A, S, F, N>
+where
+	A: Active,
+	S: Strategy,
+{
+	/// How far the strategy of input `i` is from settling, without updating
+	/// it
+	///
+	/// # Panics
+	/// If `i` is not less than `N`
+	pub fn progress(&self, i: usize) -> Progress {
+		self.strategies[i].progress(self.get(i))
+	}
+
+	/// Updates each strategy with a sample of its input: high if bit `i` of
+	/// `samples` is set
+	pub fn update(&self, samples: u64) -> Changes {
+		let before = self.settled.get();
+		let mut after = before;
+		for (i, strategy) in self.strategies.iter().enumerate() {
+			match strategy.update((samples & 1 << i != 0).into()) {
+				Some(Status::High) => after |= 1 << i,
+				Some(Status::Low) => after &= !(1 << i),
+				None => {}
+			}
+		}
+		self.settled.set(after);
+		let changed = before ^ after;
+		let activated = changed & !(after ^ Self::ACTIVE);
+		Changes {
+			changed,
+			activated,
+			deactivated: changed & !activated,
+		}
+	}
+}
+
+impl<A, S, F, const N: usize> DebouncedGroup<A, S, F, N>
+where
+	A: Active,
+	S: Strategy,
+	F: InputSource,
+{
+	/// Samples every input, then updates each strategy
+	pub fn poll(&self) -> Changes {
+		let samples = self
+			.inputs
+			.iter()
+			.enumerate()
+			.filter(|(_, input)| input.read() == Status::High)
+			.fold(0, |samples, (i, _)| samples | 1 << i);
+		self.update(samples)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::Integrator,
+	};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn poll() {
+		let port = Cell::new(0b111u8);
+		let inputs = array::from_fn(|i| {
+			let port = &port;
+			move || port.get() & 1 << i != 0
+		});
+		let g = DebouncedGroup::<Low, _, _, 3>::new(inputs, || {
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap())
+		});
+		assert_eq!(g.poll(), Changes::default());
+		assert_eq!(g.triggered(), 0);
+
+		port.set(0b010);
+		assert!(g.poll().is_empty());
+		assert_eq!(g.progress(0), Progress::new(Status::Low, 1, 2));
+		assert_eq!(
+			g.poll(),
+			Changes {
+				changed: 0b101,
+				activated: 0b101,
+				deactivated: 0,
+			}
+		);
+		assert_eq!(g.get(0), Status::Low);
+		assert!(!g.is_triggered(1));
+		assert_eq!(g.triggered(), 0b101);
+		assert_eq!(g.settled(), 0b010);
+
+		port.set(0b011);
+		g.poll();
+		assert_eq!(
+			g.poll(),
+			Changes {
+				changed: 0b001,
+				activated: 0,
+				deactivated: 0b001,
+			}
+		);
+	}
+
+	#[test]
+	fn update() {
+		let g = DebouncedGroup::<High, _, (), 64>::new([(); 64], || {
+			Integrator::new::<High>(NonZeroU8::new(2).unwrap())
+		});
+		assert_eq!(g.settled(), 0);
+		g.update(1 << 63 | 1);
+		let changes = g.update(1 << 63 | 1);
+		assert_eq!(changes.activated, 1 << 63 | 1);
+		assert_eq!(g.triggered(), 1 << 63 | 1);
+		assert!(g.is_triggered(63));
+	}
+
+	#[test]
+	#[should_panic]
+	fn out_of_range() {
+		let g = DebouncedGroup::<High, _, (), 2>::new([(); 2], || {
+			Integrator::new::<High>(NonZeroU8::new(2).unwrap())
+		});
+		g.get(2);
+	}
+}
@@ -0,0 +1,275 @@
+//! # `embedded-hal` Integration
+//! Debounce any [`embedded_hal::digital::InputPin`] by wrapping it in a
+//! [`PinInput`], or create the input directly with [`Debounced::from_pin`] and
+//! [`TryDebounced::from_pin`].
+//!
+//! Conversely, [`Debounced`] and [`TryDebounced`] inputs (and shared
+//! references to them) are [`InputPin`]s, so they can be handed to drivers
+//! which expect a plain pin. Reading the pin updates the input and reports
+//! the level it last settled on (as in [`Debounced::get_latest`]), whichever
+//! value is [active](trait@Active).
+
+use crate::{
+	active::Active, strategy::Strategy, Debounced, ErrorPolicy, FallibleInput, InputSource, Status,
+	TryDebounced,
+};
+use core::{cell::RefCell, convert::Infallible};
+use embedded_hal::digital::{Error, ErrorType, InputPin};
+
+/// # Input Pin as a Source of Samples
+/// Reads an [`InputPin`] through a [`RefCell`], since reading a pin needs
+/// `&mut` access.
+///
+/// If the pin can fail, it is a [`FallibleInput`] (for a [`TryDebounced`]
+/// input), and the pin's error is returned. If it can not fail (its error is
+/// [`Infallible`]), it is also an [`InputSource`] (for a [`Debounced`] input).
+pub struct PinInput<P> {
+	pin: RefCell<P>,
+}
+
+impl<P> PinInput<P> {
+	/// Wraps the `pin`
+	pub const fn new(pin: P) -> Self {
+		Self {
+			pin: RefCell::new(pin),
+		}
+	}
+
+	/// Releases the pin
+	pub fn release(self) -> P {
+		self.pin.into_inner()
+	}
+}
+
+impl<P: InputPin> FallibleInput for PinInput<P> {
+	type Error = P::Error;
+
+	#[inline]
+	fn try_read(&self) -> Result<Status, P::Error> {
+		self.pin.borrow_mut().is_high().map(Into::into)
+	}
+}
+
+impl<P: InputPin<Error = Infallible>> InputSource for PinInput<P> {
+	#[inline]
+	fn read(&self) -> Status {
+		match self.try_read() {
+			Ok(status) => status,
+			Err(e) => match e {},
+		}
+	}
+}
+
+impl<A, S, P> Debounced<A, S, PinInput<P>>
+where
+	A: Active,
+	P: InputPin<Error = Infallible>,
+{
+	/// Creates a new Debounced input reading a `pin` which can not fail
+	pub const fn from_pin(strategy: S, pin: P) -> Self {
+		Self::new(strategy, PinInput::new(pin))
+	}
+}
+
+impl<A, S, P> TryDebounced<A, S, PinInput<P>>
+where
+	A: Active,
+	P: InputPin,
+{
+	/// Creates a new TryDebounced input reading a `pin`, handling its errors
+	/// with the `policy`
+	pub const fn from_pin(strategy: S, pin: P, policy: ErrorPolicy) -> Self {
+		Self::new(strategy, PinInput::new(pin), policy)
+	}
+}
+
+impl<A, S, F> ErrorType for Debounced<A, S, F> {
+	type Error = Infallible;
+}
+
+impl<A, S, F> InputPin for Debounced<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: InputSource,
+{
+	fn is_high(&mut self) -> Result<bool, Infallible> {
+		(&*self).is_high()
+	}
+
+	fn is_low(&mut self) -> Result<bool, Infallible> {
+		(&*self).is_low()
+	}
+}
+
+impl<A, S, F> InputPin for &Debounced<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: InputSource,
+{
+	fn is_high(&mut self) -> Result<bool, Infallible> {
+		Ok(self.get_latest() == Status::High)
+	}
+
+	fn is_low(&mut self) -> Result<bool, Infallible> {
+		Ok(self.get_latest() == Status::Low)
+	}
+}
+
+impl<A, S, F> ErrorType for TryDebounced<A, S, F>
+where
+	F: FallibleInput,
+	F::Error: Error,
+{
+	type Error = F::Error;
+}
+
+impl<A, S, F> InputPin for TryDebounced<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: FallibleInput,
+	F::Error: Error,
+{
+	fn is_high(&mut self) -> Result<bool, F::Error> {
+		(&*self).is_high()
+	}
+
+	fn is_low(&mut self) -> Result<bool, F::Error> {
+		(&*self).is_low()
+	}
+}
+
+impl<A, S, F> InputPin for &TryDebounced<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: FallibleInput,
+	F::Error: Error,
+{
+	fn is_high(&mut self) -> Result<bool, F::Error> {
+		Ok(self.get_latest()? == Status::High)
+	}
+
+	fn is_low(&mut self) -> Result<bool, F::Error> {
+		Ok(self.get_latest()? == Status::Low)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::Integrator,
+	};
+	use core::num::NonZeroU8;
+	use embedded_hal::digital::ErrorKind;
+
+	/// A pin reading from a list of levels, failing on `None`
+	struct ScriptedPin<'a>(&'a [Option<bool>]);
+
+	#[derive(Debug, PartialEq)]
+	struct Fault;
+
+	impl Error for Fault {
+		fn kind(&self) -> ErrorKind {
+			ErrorKind::Other
+		}
+	}
+
+	impl ErrorType for ScriptedPin<'_> {
+		type Error = Fault;
+	}
+
+	impl InputPin for ScriptedPin<'_> {
+		fn is_high(&mut self) -> Result<bool, Fault> {
+			let (level, rest) = self.0.split_first().expect("script ended");
+			self.0 = rest;
+			level.ok_or(Fault)
+		}
+
+		fn is_low(&mut self) -> Result<bool, Fault> {
+			self.is_high().map(|level| !level)
+		}
+	}
+
+	/// A pin which can not fail
+	struct Grounded;
+
+	impl ErrorType for Grounded {
+		type Error = Infallible;
+	}
+
+	impl InputPin for Grounded {
+		fn is_high(&mut self) -> Result<bool, Infallible> {
+			Ok(false)
+		}
+
+		fn is_low(&mut self) -> Result<bool, Infallible> {
+			Ok(true)
+		}
+	}
+
+	#[test]
+	fn infallible() {
+		let d = Debounced::<Low, _, _>::from_pin(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Grounded,
+		);
+		assert!(d.is_triggered_blocking());
+	}
+
+	#[test]
+	fn fallible() {
+		let script = [Some(true), None, Some(true), None, None, Some(false)];
+		let d = TryDebounced::<High, _, _>::from_pin(
+			Integrator::new::<High>(NonZeroU8::new(2).unwrap()),
+			ScriptedPin(&script),
+			ErrorPolicy::FaultLimit(NonZeroU8::new(2).unwrap()),
+		);
+		assert_eq!(d.try_is_triggered(), Ok(None));
+		assert_eq!(d.try_is_triggered(), Ok(None));
+		assert_eq!(d.try_is_triggered(), Ok(Some(true)));
+		assert_eq!(d.try_is_triggered(), Ok(None));
+		assert_eq!(d.try_is_triggered(), Err(Fault));
+		assert_eq!(d.is_triggered_latest(), Ok(true));
+	}
+
+	#[test]
+	fn release() {
+		let pin = PinInput::new(ScriptedPin(&[Some(true)]));
+		assert_eq!(pin.try_read(), Ok(Status::High));
+		assert!(pin.release().0.is_empty());
+	}
+
+	/// A driver which only knows about pins
+	fn wait_pressed<P: InputPin>(mut pin: P) -> Result<usize, P::Error> {
+		let mut polls = 1;
+		while pin.is_high()? {
+			polls += 1;
+		}
+		Ok(polls)
+	}
+
+	#[test]
+	fn as_pin() {
+		let mut d = Debounced::<Low, _, _>::from_pin(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Grounded,
+		);
+		assert_eq!(wait_pressed(&d), Ok(2));
+		assert_eq!(d.is_high(), Ok(false));
+		assert_eq!(d.is_low(), Ok(true));
+		assert_eq!(wait_pressed(d), Ok(1));
+
+		let script = [Some(false), None];
+		let d = TryDebounced::<Low, _, _>::from_pin(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			ScriptedPin(&script),
+			ErrorPolicy::Propagate,
+		);
+		assert_eq!(wait_pressed(&d), Err(Fault));
+	}
+}
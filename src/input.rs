@@ -0,0 +1,150 @@
+use crate::Status;
+use core::cell::{Cell, RefCell};
+#[cfg(target_has_atomic = "8")]
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// # Source of Input Samples
+/// Reads the raw (bouncing) value of an input each time a [`Debounced`]
+/// input is updated.
+///
+/// Implemented for:
+/// - closures and `fn`s returning a `bool` or a [`Status`] (or anything else
+///   which converts into a [`Status`])
+/// - references to a [`Cell<bool>`] or an [`AtomicBool`] (eg. a flag set by an
+///   interrupt), read with [`Ordering::Relaxed`]
+/// - a [`RefCell`] of an [`FnMut`], for sources which need to mutate their
+///   state (panics if the source is re-entered)
+///
+/// Implement it for a pin driver or register to plug it in directly.
+///
+/// [`Debounced`]: crate::Debounced
+pub trait InputSource {
+	/// Samples the input
+	fn read(&self) -> Status;
+}
+
+impl<F, O> InputSource for F
+where
+	F: Fn() -> O,
+	O: Into<Status>,
+{
+	#[inline]
+	fn read(&self) -> Status {
+		self().into()
+	}
+}
+
+impl InputSource for &Cell<bool> {
+	#[inline]
+	fn read(&self) -> Status {
+		self.get().into()
+	}
+}
+
+#[cfg(target_has_atomic = "8")]
+impl InputSource for &AtomicBool {
+	#[inline]
+	fn read(&self) -> Status {
+		self.load(Ordering::Relaxed).into()
+	}
+}
+
+impl<F, O> InputSource for RefCell<F>
+where
+	F: FnMut() -> O,
+	O: Into<Status>,
+{
+	#[inline]
+	fn read(&self) -> Status {
+		(self.borrow_mut())().into()
+	}
+}
+
+/// # Source of Input Samples which can Fail
+/// Reads the raw value of an input for a [`TryDebounced`] input, which
+/// handles errors with its [`ErrorPolicy`](crate::ErrorPolicy).
+///
+/// Implemented for closures and `fn`s returning a [`Result`] of a `bool` or a
+/// [`Status`] (eg. reading a GPIO expander over I2C).
+///
+/// [`TryDebounced`]: crate::TryDebounced
+pub trait FallibleInput {
+	/// The error from reading the input
+	type Error;
+
+	/// Samples the input
+	fn try_read(&self) -> Result<Status, Self::Error>;
+}
+
+impl<F, O, E> FallibleInput for F
+where
+	F: Fn() -> Result<O, E>,
+	O: Into<Status>,
+{
+	type Error = E;
+
+	#[inline]
+	fn try_read(&self) -> Result<Status, E> {
+		self().map(Into::into)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, Debounced};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn closures() {
+		assert_eq!((|| true).read(), Status::High);
+		assert_eq!((|| Status::Low).read(), Status::Low);
+		fn released() -> bool {
+			true
+		}
+		assert_eq!(released.read(), Status::High);
+	}
+
+	#[test]
+	fn references() {
+		let bit = Cell::new(false);
+		assert_eq!((&bit).read(), Status::Low);
+		bit.set(true);
+		assert_eq!((&bit).read(), Status::High);
+
+		let flag = AtomicBool::new(true);
+		assert_eq!((&flag).read(), Status::High);
+		flag.store(false, Ordering::Relaxed);
+		assert_eq!((&flag).read(), Status::Low);
+	}
+
+	#[test]
+	fn stateful() {
+		let mut count = 0;
+		let source = RefCell::new(move || {
+			count += 1;
+			count > 2
+		});
+		assert_eq!(source.read(), Status::Low);
+		assert_eq!(source.read(), Status::Low);
+		assert_eq!(source.read(), Status::High);
+	}
+
+	#[test]
+	fn fallible() {
+		assert_eq!((|| Ok::<_, ()>(true)).try_read(), Ok(Status::High));
+		assert_eq!((|| Err::<bool, _>(3)).try_read(), Err(3));
+	}
+
+	#[test]
+	fn debounced() {
+		let flag = AtomicBool::new(false);
+		let d = Debounced::<Low, _, _>::with_integrator(NonZeroU8::new(2).unwrap(), &flag);
+		assert!(d.is_triggered_blocking());
+		flag.store(true, Ordering::Relaxed);
+		assert!(!d.is_triggered_blocking());
+
+		let d = Debounced::<Low, _, _>::with_integrator(NonZeroU8::new(2).unwrap(), || Status::Low);
+		assert!(d.is_triggered_blocking());
+	}
+}
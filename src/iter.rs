@@ -0,0 +1,174 @@
+use crate::{active::Active, strategy::Strategy, tune::Edge, Status};
+use core::marker::PhantomData;
+
+/// # Debouncing Recorded Samples
+/// Debounces any iterator of samples (`bool`s or [`Status`]es) with any
+/// [`Strategy`], without allocating:
+///
+/// ```
+/// use bounced::{active::Low, strategy::Integrator, DebounceExt, Status};
+/// use core::num::NonZeroU8;
+///
+/// let samples = [true, false, true, false, false, true];
+/// let integrator = Integrator::new::<Low>(NonZeroU8::new(2).unwrap());
+/// let edges = samples.iter().copied().debounce::<Low>(integrator).edges();
+/// assert!(edges.map(|e| (e.index, e.status)).eq([(4, Status::Low)]));
+/// ```
+///
+/// The trait is generic over the strategy `S`, so that only the active value
+/// needs to be named when calling [`Self::debounce`].
+pub trait DebounceExt<S: Strategy>: Iterator + Sized
+where
+	Self::Item: Into<Status>,
+{
+	/// Updates the `strategy` with each sample, yielding the result
+	/// ([`Strategy::update`]) of each
+	fn debounce<A: Active>(self, strategy: S) -> Debounce<Self, A, S> {
+		Debounce {
+			samples: self,
+			strategy,
+			index: 0,
+			settled: !A::ACTIVE_VALUE,
+			_a: PhantomData,
+		}
+	}
+}
+
+impl<I, S> DebounceExt<S> for I
+where
+	I: Iterator,
+	I::Item: Into<Status>,
+	S: Strategy,
+{
+}
+
+/// # Debounced Samples
+/// Yields the result of updating the strategy with each sample. Created with
+/// [`DebounceExt::debounce`].
+pub struct Debounce<I, A, S> {
+	samples: I,
+	strategy: S,
+	index: usize,
+	settled: Status,
+	_a: PhantomData<fn() -> A>, // No A is owned or referenced
+}
+
+impl<I, A, S> Debounce<I, A, S> {
+	/// Only yield the samples at which the strategy settled on a different
+	/// value. Before the first sample, it was settled on the
+	/// [inactive](trait@Active) value.
+	pub fn edges(self) -> Edges<I, A, S> {
+		Edges(self)
+	}
+}
+
+impl<I, A, S> Iterator for Debounce<I, A, S>
+where
+	I: Iterator,
+	I::Item: Into<Status>,
+	S: Strategy,
+{
+	type Item = Option<Status>;
+
+	fn next(&mut self) -> Option<Option<Status>> {
+		let status = self.strategy.update(self.samples.next()?.into());
+		self.index += 1;
+		if let Some(s) = status {
+			self.settled = s;
+		}
+		Some(status)
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.samples.size_hint()
+	}
+}
+
+/// # Debounced Edges of Samples
+/// Yields the index of each sample at which the strategy settled on a
+/// different value, and that value. Created with [`Debounce::edges`].
+pub struct Edges<I, A, S>(Debounce<I, A, S>);
+
+impl<I, A, S> Iterator for Edges<I, A, S>
+where
+	I: Iterator,
+	I::Item: Into<Status>,
+	S: Strategy,
+{
+	type Item = Edge;
+
+	fn next(&mut self) -> Option<Edge> {
+		loop {
+			let last = self.0.settled;
+			match self.0.next()? {
+				Some(status) if status != last => {
+					return Some(Edge {
+						index: self.0.index - 1,
+						status,
+					})
+				}
+				_ => {}
+			}
+		}
+	}
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(0, self.0.size_hint().1)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::{Integrator, Majority},
+	};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn debounce() {
+		let samples = [false, false, true, true, true];
+		let integrator = Integrator::new::<High>(NonZeroU8::new(2).unwrap());
+		let mut d = samples.iter().copied().debounce::<High>(integrator);
+		assert_eq!(d.size_hint(), (5, Some(5)));
+		assert_eq!(d.next(), Some(Some(Status::Low)));
+		assert_eq!(d.next(), Some(Some(Status::Low)));
+		assert_eq!(d.next(), Some(None));
+		assert_eq!(d.next(), Some(Some(Status::High)));
+		assert_eq!(d.next(), Some(Some(Status::High)));
+		assert_eq!(d.next(), None);
+	}
+
+	#[test]
+	fn edges() {
+		let samples = [
+			Status::High,
+			Status::Low,
+			Status::Low,
+			Status::High,
+			Status::Low,
+			Status::Low,
+			Status::High,
+			Status::High,
+			Status::High,
+		];
+		let majority = Majority::<u8, 2, 3>::new::<Low>();
+		let mut edges = samples.iter().copied().debounce::<Low>(majority).edges();
+		assert_eq!(
+			edges.next(),
+			Some(Edge {
+				index: 2,
+				status: Status::Low
+			})
+		);
+		assert_eq!(
+			edges.next(),
+			Some(Edge {
+				index: 7,
+				status: Status::High
+			})
+		);
+		assert_eq!(edges.next(), None);
+	}
+}
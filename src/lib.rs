@@ -6,15 +6,47 @@
 #![warn(missing_docs)]
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+mod config;
 mod debounced;
+#[cfg(feature = "std")]
+mod events;
+mod fallible;
+mod group;
+mod input;
+mod iter;
+mod observe;
+mod packed;
+mod query;
 mod status;
+mod toggle;
+#[cfg(feature = "async")]
+mod wait;
 
+pub use config::{ConfigError, DebounceConfig};
 pub use debounced::{
-	Debounced, DebouncedGenericShift, DebouncedIntegrandShift, DebouncedIntegrator,
+	Debounced, DebouncedFn, DebouncedGenericShift, DebouncedIntegrandShift, DebouncedIntegrator,
 };
+#[cfg(feature = "futures")]
+pub use events::EventStream;
+#[cfg(feature = "std")]
+pub use events::{Event, Events};
+pub use fallible::{ErrorPolicy, TryDebounced};
+pub use group::{Changes, DebouncedGroup};
+pub use input::{FallibleInput, InputSource};
+pub use iter::{Debounce, DebounceExt, Edges};
+pub use observe::{Handler, Handlers, Observed, Observer};
+pub use packed::PackedDebounced;
 pub use status::Status;
+pub use toggle::Toggle;
 pub mod active;
+#[cfg(feature = "embedded-hal")]
+pub mod hal;
+pub mod sim;
 pub mod strategy;
+pub mod tune;
 
 mod private {
 	pub trait Sealed {}
@@ -42,6 +74,24 @@ pub const fn samples(sample_freq: usize, hold_time_ms: usize) -> usize {
 	}
 }
 
+/// # Computes the hold time
+///
+/// The inverse of [`samples`]: computes the time (in ms) taken by `samples`
+/// samples at the `sample_freq` (in Hz). If a partial millisecond will have
+/// occurred, rounds up.
+///
+/// A `sample_freq` of `0` gives `0`.
+#[inline]
+pub const fn hold_time_ms(sample_freq: usize, samples: usize) -> usize {
+	let ms_times_freq = samples * 1000;
+	// ceiling division
+	if ms_times_freq > 0 && sample_freq > 0 {
+		1 + (ms_times_freq - 1) / sample_freq
+	} else {
+		0
+	}
+}
+
 #[cfg(test)]
 mod test_samples {
 	use super::*;
@@ -67,4 +117,14 @@ mod test_samples {
 		assert_eq!(naive_sample_impl(250, 5), 1);
 		assert_eq!(samples(250, 5), 2);
 	}
+
+	#[test]
+	fn hold_time_inverse() {
+		assert_eq!(hold_time_ms(1000, 5), 5);
+		assert_eq!(hold_time_ms(250, 1), 4);
+		assert_eq!(hold_time_ms(250, 2), 8);
+		assert_eq!(hold_time_ms(300, 1), 4);
+		assert_eq!(samples(250, hold_time_ms(250, 3)), 3);
+		assert_eq!(hold_time_ms(0, 7), 0);
+	}
 }
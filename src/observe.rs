@@ -0,0 +1,274 @@
+use crate::{
+	active::Active,
+	query::queries,
+	strategy::{Progress, Strategy},
+	Debounced, InputSource, Status,
+};
+use core::cell::Cell;
+
+/// # Reacting to Changes of an Input
+/// Called by an [`Observed`] input when it settles on a different value, or
+/// starts to change. Every function does nothing by default.
+///
+/// Since inputs are updated through shared references (eg. in a timer
+/// interrupt), the observer is too. Store any state in [`Cell`]s.
+pub trait Observer {
+	/// The input settled on the [active](trait@Active) value
+	fn on_activated(&self) {}
+	/// The input settled on the [inactive](trait@Active) value
+	fn on_deactivated(&self) {}
+	/// The input became unstable, after being settled
+	fn on_unstable(&self) {}
+}
+
+impl Observer for () {}
+
+/// # A Callback of [`Handlers`]
+/// Any `Fn()`, or `()` to do nothing.
+pub trait Handler {
+	/// Calls the handler
+	fn call(&self);
+}
+
+impl Handler for () {
+	fn call(&self) {}
+}
+
+impl<F: Fn()> Handler for F {
+	fn call(&self) {
+		self()
+	}
+}
+
+/// # An Observer of Closures
+/// Calls a [`Handler`] for each change. Start with [`Handlers::new`], which
+/// does nothing, and set the handlers which are needed.
+///
+/// The handlers are stored by type, so no allocation or dynamic dispatch is
+/// needed.
+#[derive(Debug, Copy, Clone)]
+pub struct Handlers<FA = (), FD = (), FU = ()> {
+	activated: FA,
+	deactivated: FD,
+	unstable: FU,
+}
+
+impl Handlers {
+	/// Create new Handlers which do nothing
+	pub const fn new() -> Self {
+		Self {
+			activated: (),
+			deactivated: (),
+			unstable: (),
+		}
+	}
+}
+
+impl Default for Handlers {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<FA, FD, FU> Handlers<FA, FD, FU> {
+	/// Calls `f` when the input settles on the [active](trait@Active) value
+	pub fn on_activated<F: Fn()>(self, f: F) -> Handlers<F, FD, FU> {
+		Handlers {
+			activated: f,
+			deactivated: self.deactivated,
+			unstable: self.unstable,
+		}
+	}
+
+	/// Calls `f` when the input settles on the [inactive](trait@Active) value
+	pub fn on_deactivated<F: Fn()>(self, f: F) -> Handlers<FA, F, FU> {
+		Handlers {
+			activated: self.activated,
+			deactivated: f,
+			unstable: self.unstable,
+		}
+	}
+
+	/// Calls `f` when the input becomes unstable
+	pub fn on_unstable<F: Fn()>(self, f: F) -> Handlers<FA, FD, F> {
+		Handlers {
+			activated: self.activated,
+			deactivated: self.deactivated,
+			unstable: f,
+		}
+	}
+}
+
+impl<FA, FD, FU> Observer for Handlers<FA, FD, FU>
+where
+	FA: Handler,
+	FD: Handler,
+	FU: Handler,
+{
+	fn on_activated(&self) {
+		self.activated.call();
+	}
+
+	fn on_deactivated(&self) {
+		self.deactivated.call();
+	}
+
+	fn on_unstable(&self) {
+		self.unstable.call();
+	}
+}
+
+/// # Observed Input
+/// Calls the [`Observer`] as the [`Debounced`] input changes, so inputs can
+/// be wired to actions rather than checked for changes after every update.
+///
+/// The input becomes unstable when the `strategy` stops reporting a settled
+/// value, and is stable again once it settles on either value.
+///
+/// Every query updates the input like those of [`Debounced`], calling the
+/// observer for any change.
+pub struct Observed<A, S, F, O> {
+	debounced: Debounced<A, S, F>,
+	observer: O,
+	unstable: Cell<bool>,
+}
+
+impl<A, S, F, O> Observed<A, S, F, O> {
+	/// Create a new Observed input
+	pub const fn new(debounced: Debounced<A, S, F>, observer: O) -> Self {
+		Self {
+			debounced,
+			observer,
+			unstable: Cell::new(false),
+		}
+	}
+
+	/// The observer
+	pub fn observer(&self) -> &O {
+		&self.observer
+	}
+
+	/// Stops observing the input
+	pub fn into_inner(self) -> (Debounced<A, S, F>, O) {
+		(self.debounced, self.observer)
+	}
+}
+
+impl<A, S, F, O> Observed<A, S, F, O>
+where
+	A: Active,
+	S: Strategy,
+	F: InputSource,
+	O: Observer,
+{
+	/// The last settled value, without updating the `strategy`.
+	pub fn last_settled(&self) -> Status {
+		self.debounced.last_settled()
+	}
+
+	/// How far the `strategy` is from settling, without updating it
+	pub fn progress(&self) -> Progress {
+		self.debounced.progress()
+	}
+
+	/// Updates the input, calling the observer for any change.
+	///
+	/// If the `strategy` has not settled on a [`Status`], will not pick one.
+	pub fn try_get(&self) -> Option<Status> {
+		let last = self.debounced.last_settled();
+		let status = self.debounced.try_get();
+		if status.is_some() {
+			self.unstable.set(false);
+		}
+		match status {
+			Some(s) if s == last => {}
+			Some(s) if s == A::ACTIVE_VALUE => self.observer.on_activated(),
+			Some(_) => self.observer.on_deactivated(),
+			None if !self.unstable.replace(true) => self.observer.on_unstable(),
+			None => {}
+		}
+		status
+	}
+
+	queries!(A);
+}
+
+impl<A, S, F> Debounced<A, S, F> {
+	/// Calls the `observer` as the input changes
+	pub const fn observe<O: Observer>(self, observer: O) -> Observed<A, S, F, O> {
+		Observed::new(self, observer)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, DebouncedIntegrator};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn handlers() {
+		let bit = Cell::new(false);
+		let (presses, releases, bounces) = (Cell::new(0), Cell::new(0), Cell::new(0));
+		let handlers = Handlers::new()
+			.on_activated(|| presses.set(presses.get() + 1))
+			.on_deactivated(|| releases.set(releases.get() + 1))
+			.on_unstable(|| bounces.set(bounces.get() + 1));
+		let d = DebouncedIntegrator::<Low, _>::with_integrator(NonZeroU8::new(2).unwrap(), || {
+			!bit.get()
+		})
+		.observe(handlers);
+		let counts = || (presses.get(), releases.get(), bounces.get());
+
+		assert_eq!(d.try_get(), Some(Status::High));
+		assert_eq!(counts(), (0, 0, 0));
+		bit.set(true);
+		assert_eq!(d.try_is_triggered(), None);
+		assert_eq!(d.try_is_triggered(), Some(true));
+		assert_eq!(counts(), (1, 0, 1));
+		// bouncing back does not settle, and holding does not repeat
+		bit.set(false);
+		assert!(d.is_triggered_latest());
+		bit.set(true);
+		assert!(d.is_triggered_latest());
+		assert!(d.is_triggered_latest());
+		assert_eq!(counts(), (1, 0, 2));
+		bit.set(false);
+		d.get_latest();
+		d.get_latest();
+		d.get_latest();
+		assert_eq!(counts(), (1, 1, 3));
+	}
+
+	#[test]
+	fn observer() {
+		struct Count(Cell<u8>);
+
+		impl Observer for Count {
+			fn on_activated(&self) {
+				self.0.set(self.0.get() + 1);
+			}
+		}
+
+		let bit = Cell::new(true);
+		let d = Observed::new(
+			DebouncedIntegrator::<Low, _>::with_integrator(NonZeroU8::new(2).unwrap(), || {
+				!bit.get()
+			}),
+			Count(Cell::new(0)),
+		);
+		assert_eq!(d.get_latest(), Status::High);
+		assert_eq!(d.get_latest(), Status::Low);
+		assert_eq!(d.observer().0.get(), 1);
+		let (d, _) = d.into_inner();
+		assert_eq!(d.last_settled(), Status::Low);
+
+		let d = DebouncedIntegrator::<Low, _>::with_integrator(NonZeroU8::new(2).unwrap(), || {
+			!bit.get()
+		})
+		.observe(());
+		assert_eq!(d.try_is_triggered(), None);
+		assert_eq!(d.last_settled(), Status::High);
+		assert_eq!(d.progress(), Progress::new(Status::Low, 1, 2));
+	}
+}
@@ -0,0 +1,117 @@
+use crate::{
+	active::Active,
+	query::queries,
+	strategy::{PackedIntegrator, Progress, Strategy},
+	InputSource, Status,
+};
+use core::marker::PhantomData;
+
+/// # Debounces Input in One Byte
+/// A [`Debounced`](crate::Debounced) input using the [`PackedIntegrator<N>`]
+/// strategy, which stores the last settled value in the top bit of its
+/// counter. For many inputs in little RAM.
+///
+/// `N` must be at most 127, otherwise creating the input fails to compile.
+/// The first stored value will be [`!Active::ACTIVE_VALUE`](trait@Active).
+///
+/// With an input which has no size (eg. a `fn` item or a closure which
+/// captures nothing), the whole input is one byte.
+pub struct PackedDebounced<A, F, const N: u8> {
+	input: F,
+	strategy: PackedIntegrator<N>,
+	_a: PhantomData<fn() -> A>, // No A is owned or referenced
+}
+
+impl<A, F, const N: u8> PackedDebounced<A, F, N>
+where
+	A: Active,
+{
+	/// Creates a new PackedDebounced input
+	pub const fn new(input: F) -> Self {
+		Self {
+			input,
+			strategy: PackedIntegrator::new::<A>(),
+			_a: PhantomData,
+		}
+	}
+
+	/// The last settled value, without updating the `strategy`.
+	pub fn last_settled(&self) -> Status {
+		self.strategy.last_settled()
+	}
+
+	/// How far the `strategy` is from settling, without updating it
+	pub fn progress(&self) -> Progress {
+		self.strategy.progress(self.last_settled())
+	}
+}
+
+impl<A, F, const N: u8> PackedDebounced<A, F, N>
+where
+	A: Active,
+	F: InputSource,
+{
+	/// If the `strategy` has not settled on a [`Status`], will not pick one.
+	///
+	/// If you just want to update the status of an input (eg. in a timer
+	/// interrupt), use this function.
+	pub fn try_get(&self) -> Option<Status> {
+		self.strategy.update(self.input.read())
+	}
+
+	queries!(A);
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		DebouncedIntegrandShift,
+	};
+
+	#[test]
+	fn low_is_triggered() {
+		let d = PackedDebounced::<Low, _, 3>::new(|| false);
+		assert_eq!(d.try_is_triggered(), None);
+		assert!(!d.is_triggered_or_unset());
+		assert!(d.is_triggered_latest());
+		assert!(d.is_triggered_blocking());
+	}
+
+	#[test]
+	fn high_counts_to_limit() {
+		let d = PackedDebounced::<High, _, 127>::new(|| true);
+		for _ in 0..126 {
+			assert_eq!(d.try_get(), None);
+		}
+		assert_eq!(d.get_latest(), Status::High);
+		assert!(d.is_triggered_latest());
+		assert_eq!(d.last_settled(), Status::High);
+	}
+
+	#[test]
+	fn size() {
+		use core::mem::size_of_val;
+		fn released() -> bool {
+			true
+		}
+		assert_eq!(size_of_val(&PackedDebounced::<Low, _, 6>::new(|| false)), 1);
+		assert_eq!(
+			size_of_val(&PackedDebounced::<High, _, 127>::new(released)),
+			1
+		);
+		assert_eq!(
+			size_of_val(&DebouncedIntegrandShift::<Low, _, 6>::with(|| false)),
+			2
+		);
+	}
+
+	#[test]
+	fn progress() {
+		let d = PackedDebounced::<Low, _, 3>::new(|| false);
+		assert_eq!(d.progress(), Progress::new(Status::High, 3, 3));
+		d.try_get();
+		assert_eq!(d.progress(), Progress::new(Status::Low, 1, 3));
+	}
+}
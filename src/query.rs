@@ -0,0 +1,97 @@
+/// Implements the queries of a debounced input on top of its `try_get` and
+/// `last_settled`, so every input reads the same way. Invoke it in an `impl`
+/// block where the [active](trait@crate::active::Active) value is `$a`.
+///
+/// With an error type, `try_get` returns a `Result`, and so does every query.
+macro_rules! queries {
+	($a:ident) => {
+		/// If the `strategy` has not settled on a [`Status`], uses the last
+		/// settled value.
+		pub fn get_latest(&self) -> Status {
+			self.try_get().unwrap_or_else(|| self.last_settled())
+		}
+
+		/// Blocks until the `strategy` has settled on a [`Status`] using tight
+		/// polling.
+		pub fn get_blocking(&self) -> Status {
+			loop {
+				if let Some(s) = self.try_get() {
+					return s;
+				}
+			}
+		}
+
+		/// If the `strategy` has not settled on a [`Status`], uses the
+		/// [inactive](trait@crate::active::Active) value.
+		pub fn get_or_unset(&self) -> Status {
+			self.try_get().unwrap_or(!$a::ACTIVE_VALUE)
+		}
+
+		/// Compares [`Self::try_get`] with the value of an active input
+		pub fn try_is_triggered(&self) -> Option<bool> {
+			self.try_get().map(|s| s == $a::ACTIVE_VALUE)
+		}
+
+		/// Compares [`Self::get_latest`] with the value of an active input
+		pub fn is_triggered_latest(&self) -> bool {
+			self.get_latest() == $a::ACTIVE_VALUE
+		}
+
+		/// Compares [`Self::get_blocking`] with the value of an active input
+		pub fn is_triggered_blocking(&self) -> bool {
+			self.get_blocking() == $a::ACTIVE_VALUE
+		}
+
+		/// Compares [`Self::get_or_unset`] with the value of an active input
+		pub fn is_triggered_or_unset(&self) -> bool {
+			self.get_or_unset() == $a::ACTIVE_VALUE
+		}
+	};
+	($a:ident, $error:ty) => {
+		/// If the `strategy` has not settled on a [`Status`], uses the last
+		/// settled value.
+		pub fn get_latest(&self) -> Result<Status, $error> {
+			Ok(self.try_get()?.unwrap_or_else(|| self.last_settled()))
+		}
+
+		/// Blocks until the `strategy` has settled on a [`Status`] using tight
+		/// polling.
+		///
+		/// If errors are ignored, blocks forever while the input fails.
+		pub fn get_blocking(&self) -> Result<Status, $error> {
+			loop {
+				if let Some(s) = self.try_get()? {
+					return Ok(s);
+				}
+			}
+		}
+
+		/// If the `strategy` has not settled on a [`Status`], uses the
+		/// [inactive](trait@crate::active::Active) value.
+		pub fn get_or_unset(&self) -> Result<Status, $error> {
+			Ok(self.try_get()?.unwrap_or(!$a::ACTIVE_VALUE))
+		}
+
+		/// Compares [`Self::try_get`] with the value of an active input
+		pub fn try_is_triggered(&self) -> Result<Option<bool>, $error> {
+			Ok(self.try_get()?.map(|s| s == $a::ACTIVE_VALUE))
+		}
+
+		/// Compares [`Self::get_latest`] with the value of an active input
+		pub fn is_triggered_latest(&self) -> Result<bool, $error> {
+			Ok(self.get_latest()? == $a::ACTIVE_VALUE)
+		}
+
+		/// Compares [`Self::get_blocking`] with the value of an active input
+		pub fn is_triggered_blocking(&self) -> Result<bool, $error> {
+			Ok(self.get_blocking()? == $a::ACTIVE_VALUE)
+		}
+
+		/// Compares [`Self::get_or_unset`] with the value of an active input
+		pub fn is_triggered_or_unset(&self) -> Result<bool, $error> {
+			Ok(self.get_or_unset()? == $a::ACTIVE_VALUE)
+		}
+	};
+}
+
+pub(crate) use queries;
@@ -0,0 +1,292 @@
+//! # Simulating Noisy Inputs
+//! Run a [`Strategy`] many times against randomly generated inputs to estimate
+//! how often it activates spuriously, how often it misses an activation, and
+//! how long it takes to settle.
+//!
+//! Each trial of a [`MonteCarlo`] run holds the input at the strategy's
+//! initial value for some idle samples, then changes it to the opposite
+//! (active) value and keeps it there for a window of `W` samples. The
+//! [`NoiseModel`] flips samples at random throughout, and bounces the input
+//! after the change.
+
+use crate::{strategy::Strategy, Status};
+
+/// # Pseudo-Random Numbers
+/// A small xorshift generator, so that noise can be generated without any
+/// dependencies. The same seed always generates the same numbers.
+///
+/// This is not suitable for anything but testing.
+#[derive(Debug, Clone)]
+pub struct Rng(u32);
+
+impl Rng {
+	/// Create a new generator from a `seed`
+	///
+	/// A `seed` of `0` is replaced by a non-zero value.
+	pub const fn new(seed: u32) -> Self {
+		Self(if seed == 0 { 0x9E37_79B9 } else { seed })
+	}
+
+	/// The next number in the sequence
+	pub fn next_u32(&mut self) -> u32 {
+		let mut x = self.0;
+		x ^= x << 13;
+		x ^= x >> 17;
+		x ^= x << 5;
+		self.0 = x;
+		x
+	}
+
+	/// A number in `0..n`, or `0` if `n` is `0`
+	pub fn below(&mut self, n: usize) -> usize {
+		if n == 0 {
+			0
+		} else {
+			self.next_u32() as usize % n
+		}
+	}
+
+	/// `true` with a probability of `ppm` parts per million
+	pub fn chance(&mut self, ppm: u32) -> bool {
+		self.next_u32() % 1_000_000 < ppm
+	}
+}
+
+/// # How an Input Misbehaves
+/// Probabilities are given in parts per million.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct NoiseModel {
+	/// Probability of any sample being flipped
+	pub flip_ppm: u32,
+	/// The longest bounce after the input changes, in samples. Each bounce
+	/// lasts a random number of samples up to and including this.
+	pub bounce_max: usize,
+	/// Probability of a sample during a bounce reading the old value
+	pub bounce_ppm: u32,
+}
+
+impl NoiseModel {
+	/// The sample read `since` samples after the input truly changed to
+	/// `status`
+	fn sample(&self, rng: &mut Rng, status: Status, since: usize, bounce: usize) -> Status {
+		let flipped = if since < bounce {
+			rng.chance(self.bounce_ppm)
+		} else {
+			false
+		};
+		if flipped ^ rng.chance(self.flip_ppm) {
+			!status
+		} else {
+			status
+		}
+	}
+}
+
+/// # Monte Carlo Evaluation
+/// Configures the trials run by [`MonteCarlo::run`].
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct MonteCarlo {
+	/// The noise applied to every trial
+	pub model: NoiseModel,
+	/// The number of samples before the input changes in each trial
+	pub idle: usize,
+	/// The number of trials
+	pub trials: u32,
+	/// Seeds the [`Rng`], so runs can be reproduced
+	pub seed: u32,
+}
+
+/// # Results of a [`MonteCarlo`] Run
+/// Latencies are counted in samples after the input changed, and must be less
+/// than `W`, the number of samples in each trial after the change.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Report<const W: usize> {
+	/// The number of trials run
+	pub trials: u32,
+	/// Trials where the strategy settled on the active value before the
+	/// input changed
+	pub false_triggers: u32,
+	/// Trials where the strategy never settled on the active value after the
+	/// input changed
+	pub missed: u32,
+	/// The number of trials which activated with each latency
+	pub latencies: [u32; W],
+}
+
+impl<const W: usize> Report<W> {
+	fn new(trials: u32) -> Self {
+		Self {
+			trials,
+			false_triggers: 0,
+			missed: 0,
+			latencies: [0; W],
+		}
+	}
+
+	/// The number of trials where the strategy activated after the input
+	/// changed
+	pub fn activations(&self) -> u32 {
+		self.latencies.iter().sum()
+	}
+
+	/// Spurious activations per million trials
+	pub fn false_trigger_ppm(&self) -> u32 {
+		Self::ppm(self.false_triggers, self.trials)
+	}
+
+	/// Missed activations per million trials
+	pub fn missed_ppm(&self) -> u32 {
+		Self::ppm(self.missed, self.trials)
+	}
+
+	fn ppm(count: u32, trials: u32) -> u32 {
+		(u64::from(count) * 1_000_000)
+			.checked_div(u64::from(trials))
+			.unwrap_or(0) as u32
+	}
+
+	/// The smallest latency, if any trial activated
+	pub fn min(&self) -> Option<usize> {
+		self.latencies.iter().position(|&n| n > 0)
+	}
+
+	/// The largest latency, if any trial activated
+	pub fn max(&self) -> Option<usize> {
+		self.latencies.iter().rposition(|&n| n > 0)
+	}
+
+	/// The average latency (rounded down), if any trial activated
+	pub fn mean(&self) -> Option<usize> {
+		let total: u64 = self
+			.latencies
+			.iter()
+			.enumerate()
+			.map(|(l, &n)| l as u64 * u64::from(n))
+			.sum();
+		total
+			.checked_div(u64::from(self.activations()))
+			.map(|m| m as usize)
+	}
+
+	/// The smallest latency which at least `percent` of the activations
+	/// did not exceed, if any trial activated
+	pub fn percentile(&self, percent: u8) -> Option<usize> {
+		let needed = (u64::from(self.activations()) * u64::from(percent.min(100))).div_ceil(100);
+		let mut seen = 0;
+		for (l, &n) in self.latencies.iter().enumerate() {
+			seen += u64::from(n);
+			if n > 0 && seen >= needed {
+				return Some(l);
+			}
+		}
+		None
+	}
+
+	/// The 99th percentile latency, if any trial activated
+	pub fn p99(&self) -> Option<usize> {
+		self.percentile(99)
+	}
+}
+
+impl MonteCarlo {
+	/// Runs every trial using a new strategy from `make`.
+	///
+	/// The input starts on the strategy's initial [`Strategy::status`] (or
+	/// [`Status::Low`] if it has not settled) and changes to the opposite.
+	pub fn run<S, M, const W: usize>(&self, mut make: M) -> Report<W>
+	where
+		S: Strategy,
+		M: FnMut() -> S,
+	{
+		let mut rng = Rng::new(self.seed);
+		let mut report = Report::new(self.trials);
+		for _ in 0..self.trials {
+			let strategy = make();
+			let idle = strategy.status().unwrap_or(Status::Low);
+			let active = !idle;
+
+			if (0..self.idle).any(|_| {
+				let s = self.model.sample(&mut rng, idle, usize::MAX, 0);
+				strategy.update(s) == Some(active)
+			}) {
+				report.false_triggers += 1;
+				continue;
+			}
+
+			let bounce = rng.below(self.model.bounce_max + 1);
+			let latency = (0..W).find(|&since| {
+				let s = self.model.sample(&mut rng, active, since, bounce);
+				strategy.update(s) == Some(active)
+			});
+			match latency {
+				Some(l) => report.latencies[l] += 1,
+				None => report.missed += 1,
+			}
+		}
+		report
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, strategy::Integrator};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn rng_is_reproducible() {
+		let mut a = Rng::new(7);
+		let mut b = Rng::new(7);
+		assert!((0..100).all(|_| a.next_u32() == b.next_u32()));
+		assert_ne!(Rng::new(0).next_u32(), 0);
+		assert_eq!(Rng::new(1).below(0), 0);
+		assert!(!Rng::new(3).chance(0));
+		assert!(Rng::new(3).chance(1_000_000));
+	}
+
+	#[test]
+	fn noiseless() {
+		let mc = MonteCarlo {
+			model: NoiseModel::default(),
+			idle: 10,
+			trials: 20,
+			seed: 1,
+		};
+		let r: Report<8> = mc.run(|| Integrator::new::<Low>(NonZeroU8::new(4).unwrap()));
+		assert_eq!(r.false_triggers, 0);
+		assert_eq!(r.missed, 0);
+		assert_eq!(r.activations(), 20);
+		assert_eq!(r.min(), Some(3));
+		assert_eq!(r.max(), Some(3));
+		assert_eq!(r.mean(), Some(3));
+		assert_eq!(r.p99(), Some(3));
+
+		let r: Report<3> = mc.run(|| Integrator::new::<Low>(NonZeroU8::new(4).unwrap()));
+		assert_eq!(r.missed, 20);
+		assert_eq!(r.missed_ppm(), 1_000_000);
+		assert_eq!(r.mean(), None);
+	}
+
+	#[test]
+	fn noisy() {
+		let mc = MonteCarlo {
+			model: NoiseModel {
+				flip_ppm: 100_000,
+				bounce_max: 8,
+				bounce_ppm: 500_000,
+			},
+			idle: 100,
+			trials: 1000,
+			seed: 42,
+		};
+		let twitchy: Report<64> = mc.run(|| Integrator::new::<Low>(NonZeroU8::new(1).unwrap()));
+		assert!(twitchy.false_trigger_ppm() > 900_000);
+
+		let steady: Report<64> = mc.run(|| Integrator::new::<Low>(NonZeroU8::new(8).unwrap()));
+		assert_eq!(steady.false_triggers, 0);
+		assert_eq!(steady.missed, 0);
+		assert!(steady.min().unwrap() <= steady.mean().unwrap());
+		assert!(steady.mean().unwrap() <= steady.p99().unwrap());
+		assert!(steady.p99().unwrap() <= steady.max().unwrap());
+	}
+}
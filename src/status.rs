@@ -10,10 +10,9 @@ pub enum Status {
 	High,
 }
 
-impl Not for Status {
-	type Output = Status;
-
-	fn not(self) -> Self::Output {
+impl Status {
+	/// The opposite status, usable in a `const` context
+	pub(crate) const fn invert(self) -> Status {
 		match self {
 			Status::Low => Status::High,
 			Status::High => Status::Low,
@@ -21,6 +20,14 @@ impl Not for Status {
 	}
 }
 
+impl Not for Status {
+	type Output = Status;
+
+	fn not(self) -> Self::Output {
+		self.invert()
+	}
+}
+
 impl From<Status> for bool {
 	fn from(val: Status) -> Self {
 		val == Status::High
@@ -38,6 +45,7 @@ impl From<bool> for Status {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
 	use super::*;
 
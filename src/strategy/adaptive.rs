@@ -0,0 +1,201 @@
+use crate::{
+	active::Active,
+	strategy::{Progress, Strategy},
+	Status,
+};
+use core::{cell::Cell, num::NonZeroU8};
+
+/// # Adaptive Strategy for Debouncing
+/// Learns how long the input bounces, and waits for that many identical
+/// samples to determine if an input has stabilized.
+///
+/// If the last [`threshold`](Adaptive::threshold) samples were low, the input
+/// is stable low. If they were high, the input is stable high. The strategy
+/// starts as stable on the [inactive](trait@Active) value.
+///
+/// Anywhere in-between is unstable (`None`).
+///
+/// Any run of identical samples shorter than `max` which is interrupted is
+/// counted as a bounce, so `max` should be shorter than the input is ever
+/// really held. Each time the strategy settles, the threshold is raised to one
+/// more than the longest bounce since it last settled, or else lowered half of
+/// the way towards it. The threshold starts at `max` and always stays between
+/// `min` and `max`.
+///
+/// ## Comparison to [`Integrator`](crate::strategy::Integrator)
+/// - adjusts to the input, so the latency is as low as the bounces allow
+/// - a bounce longer than any recent one can be let through once, unless `min`
+///   is large enough
+pub struct Adaptive {
+	last: Cell<Status>,
+	run: Cell<u8>,
+	longest: Cell<u8>,
+	threshold: Cell<u8>,
+	min: u8,
+	max: u8,
+}
+
+impl Adaptive {
+	/// Create a new Adaptive
+	///
+	/// A `max` less than `min` is treated as `min`.
+	pub const fn new<A: Active>(min: NonZeroU8, max: NonZeroU8) -> Self {
+		let min = min.get();
+		let max = if max.get() < min { min } else { max.get() };
+		Self {
+			last: Cell::new(A::ACTIVE_VALUE.invert()),
+			run: Cell::new(max),
+			longest: Cell::new(0),
+			threshold: Cell::new(max),
+			min,
+			max,
+		}
+	}
+
+	/// The number of identical samples currently needed to settle
+	pub fn threshold(&self) -> u8 {
+		self.threshold.get()
+	}
+
+	fn learn(&self) {
+		let needed = self.longest.replace(0).saturating_add(1);
+		let threshold = self.threshold.get();
+		let threshold = if needed > threshold {
+			needed
+		} else if needed < threshold {
+			threshold - ((threshold - needed) / 2).max(1)
+		} else {
+			threshold
+		};
+		self.threshold.set(threshold.max(self.min).min(self.max));
+	}
+}
+
+impl Strategy for Adaptive {
+	fn status(&self) -> Option<Status> {
+		if self.run.get() >= self.threshold.get() {
+			Some(self.last.get())
+		} else {
+			None
+		}
+	}
+
+	fn progress(&self, _settled: Status) -> Progress {
+		let run = self.run.get();
+		Progress::new(self.last.get(), run.into(), self.threshold.get().into())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let run = self.run.get();
+		let threshold = self.threshold.get();
+		if status == self.last.get() {
+			if run == u8::MAX {
+				return self.status();
+			}
+			self.run.set(run + 1);
+		} else {
+			if run < self.max {
+				self.longest.set(self.longest.get().max(run));
+			}
+			self.last.set(status);
+			self.run.set(1);
+		}
+
+		let run = self.run.get();
+		if run == threshold {
+			self.learn();
+			self.run.set(run.max(self.threshold.get()));
+		}
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::active::{High, Low};
+
+	#[test]
+	fn update_progress() {
+		let a = Adaptive::new::<Low>(NonZeroU8::new(1).unwrap(), NonZeroU8::new(3).unwrap());
+		assert_eq!(a.status(), Some(Status::High));
+		assert_eq!(a.threshold(), 3);
+		assert_eq!(a.update(Status::Low), None);
+		assert_eq!(a.update(Status::Low), None);
+		assert_eq!(a.update(Status::Low), Some(Status::Low));
+		assert_eq!(a.threshold(), 2);
+		assert_eq!(a.update(Status::High), None);
+		assert_eq!(a.update(Status::High), Some(Status::High));
+		assert_eq!(a.threshold(), 1);
+		// a short hold is a bounce
+		assert_eq!(a.update(Status::Low), Some(Status::Low));
+		assert_eq!(a.threshold(), 3);
+	}
+
+	#[test]
+	fn learns_bounces() {
+		let a = Adaptive::new::<High>(NonZeroU8::new(2).unwrap(), NonZeroU8::new(10).unwrap());
+		for _ in 0..8 {
+			assert_eq!(a.update(Status::High), None);
+			assert_eq!(a.update(Status::Low), None);
+		}
+		while a.update(Status::High).is_none() {}
+		assert_eq!(a.threshold(), 6);
+		for _ in 0..10 {
+			a.update(Status::High);
+		}
+
+		// bounces with runs of 4 samples
+		for _ in 0..4 {
+			a.update(Status::Low);
+		}
+		for _ in 0..4 {
+			a.update(Status::High);
+		}
+		while a.update(Status::Low) != Some(Status::Low) {}
+		assert_eq!(a.threshold(), 5);
+		for _ in 0..10 {
+			a.update(Status::Low);
+		}
+
+		// a longer bounce is let through once
+		for _ in 0..7 {
+			a.update(Status::High);
+		}
+		assert_eq!(a.status(), Some(Status::High));
+		assert_eq!(a.threshold(), 3);
+		while a.update(Status::Low) != Some(Status::Low) {}
+		assert_eq!(a.threshold(), 8);
+	}
+
+	#[test]
+	fn long_runs() {
+		let a = Adaptive::new::<High>(
+			NonZeroU8::new(u8::MAX).unwrap(),
+			NonZeroU8::new(u8::MAX).unwrap(),
+		);
+		for _ in 0..1000 {
+			assert_eq!(a.update(Status::Low), Some(Status::Low));
+		}
+		assert_eq!(a.threshold(), u8::MAX);
+	}
+
+	#[test]
+	fn bounds() {
+		let a = Adaptive::new::<High>(NonZeroU8::new(4).unwrap(), NonZeroU8::new(2).unwrap());
+		assert_eq!(a.threshold(), 4);
+		for _ in 0..3 {
+			assert_eq!(a.update(Status::High), None);
+		}
+		assert_eq!(a.update(Status::High), Some(Status::High));
+		assert_eq!(a.threshold(), 4);
+	}
+
+	#[test]
+	fn progress() {
+		let a = Adaptive::new::<Low>(NonZeroU8::new(2).unwrap(), NonZeroU8::new(4).unwrap());
+		assert_eq!(a.progress(Status::High), Progress::new(Status::High, 4, 4));
+		a.update(Status::Low);
+		assert_eq!(a.progress(Status::High), Progress::new(Status::Low, 1, 4));
+	}
+}
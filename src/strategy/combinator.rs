@@ -0,0 +1,330 @@
+use crate::{
+	strategy::{Progress, Strategy},
+	Status,
+};
+use core::cmp::Ordering;
+
+/// Compares how far `a` and `b` are towards `status`
+fn compare(a: &Progress, b: &Progress, status: Status) -> Ordering {
+	let a_done = u32::from(a.done_towards(status)) * u32::from(b.total);
+	let b_done = u32::from(b.done_towards(status)) * u32::from(a.total);
+	a_done.cmp(&b_done)
+}
+
+/// The progress of `a` or `b` towards the opposite of `settled`, picked by
+/// `pick`
+fn pick(
+	a: Progress,
+	b: Progress,
+	settled: Status,
+	pick: impl FnOnce(Ordering) -> bool,
+) -> Progress {
+	let towards = !settled;
+	let p = if pick(compare(&a, &b, towards)) { a } else { b };
+	Progress::new(towards, p.done_towards(towards).into(), p.total.into())
+}
+
+/// # Both Strategies Must Agree
+/// Every update goes to both strategies. Stable only when both strategies are
+/// stable on the same value, otherwise unstable (`None`).
+pub struct All<S1, S2> {
+	first: S1,
+	second: S2,
+}
+
+impl<S1, S2> All<S1, S2> {
+	/// Create a new All
+	pub const fn new(first: S1, second: S2) -> Self {
+		Self { first, second }
+	}
+}
+
+impl<S1, S2> Strategy for All<S1, S2>
+where
+	S1: Strategy,
+	S2: Strategy,
+{
+	fn status(&self) -> Option<Status> {
+		match (self.first.status(), self.second.status()) {
+			(Some(a), Some(b)) if a == b => Some(a),
+			_ => None,
+		}
+	}
+
+	/// The progress of the strategy which is the least done
+	fn progress(&self, settled: Status) -> Progress {
+		match self.status() {
+			Some(s) => Progress::new(s, 1, 1),
+			None => pick(
+				self.first.progress(settled),
+				self.second.progress(settled),
+				settled,
+				Ordering::is_le,
+			),
+		}
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		self.first.update(status);
+		self.second.update(status);
+		self.status()
+	}
+}
+
+/// # Either Strategy May Settle
+/// Every update goes to both strategies. Stable when either strategy is
+/// stable, unless they are stable on different values. Otherwise unstable
+/// (`None`).
+pub struct Any<S1, S2> {
+	first: S1,
+	second: S2,
+}
+
+impl<S1, S2> Any<S1, S2> {
+	/// Create a new Any
+	pub const fn new(first: S1, second: S2) -> Self {
+		Self { first, second }
+	}
+}
+
+impl<S1, S2> Strategy for Any<S1, S2>
+where
+	S1: Strategy,
+	S2: Strategy,
+{
+	fn status(&self) -> Option<Status> {
+		match (self.first.status(), self.second.status()) {
+			(Some(a), Some(b)) if a != b => None,
+			(Some(s), _) | (_, Some(s)) => Some(s),
+			(None, None) => None,
+		}
+	}
+
+	/// The progress of the strategy which is the most done
+	fn progress(&self, settled: Status) -> Progress {
+		match self.status() {
+			Some(s) => Progress::new(s, 1, 1),
+			None => pick(
+				self.first.progress(settled),
+				self.second.progress(settled),
+				settled,
+				Ordering::is_ge,
+			),
+		}
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		self.first.update(status);
+		self.second.update(status);
+		self.status()
+	}
+}
+
+/// # One Strategy After Another
+/// Every update goes to the `first` strategy, and its stable values are the
+/// updates of the `second` strategy. While the `first` strategy is unstable,
+/// the `second` is not updated.
+///
+/// The status is that of the `second` strategy.
+pub struct Chain<S1, S2> {
+	first: S1,
+	second: S2,
+}
+
+impl<S1, S2> Chain<S1, S2> {
+	/// Create a new Chain
+	pub const fn new(first: S1, second: S2) -> Self {
+		Self { first, second }
+	}
+}
+
+impl<S1, S2> Strategy for Chain<S1, S2>
+where
+	S1: Strategy,
+	S2: Strategy,
+{
+	fn status(&self) -> Option<Status> {
+		self.second.status()
+	}
+
+	/// The progress of the second strategy
+	fn progress(&self, settled: Status) -> Progress {
+		self.second.progress(settled)
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		match self.first.update(status) {
+			Some(s) => self.second.update(s),
+			None => self.status(),
+		}
+	}
+}
+
+/// # Flips a Strategy
+/// Every update is inverted before going to the strategy, and its status is
+/// inverted.
+///
+/// Create the inner strategy with the opposite [`Active`](crate::active)
+/// type, so that it starts on the right value.
+#[repr(transparent)]
+pub struct Invert<S> {
+	inner: S,
+}
+
+impl<S> Invert<S> {
+	/// Create a new Invert
+	pub const fn new(inner: S) -> Self {
+		Self { inner }
+	}
+}
+
+impl<S> Strategy for Invert<S>
+where
+	S: Strategy,
+{
+	fn status(&self) -> Option<Status> {
+		self.inner.status().map(|s| !s)
+	}
+
+	fn progress(&self, settled: Status) -> Progress {
+		let p = self.inner.progress(!settled);
+		Progress {
+			towards: !p.towards,
+			..p
+		}
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		self.inner.update(!status).map(|s| !s)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::{Integrator, LeadingEdge, Shifter},
+		Debounced,
+	};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn all() {
+		let s = All::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Integrator::new::<Low>(NonZeroU8::new(3).unwrap()),
+		);
+		assert_eq!(s.status(), Some(Status::High));
+		assert_eq!(s.update(Status::Low), None);
+		assert_eq!(s.update(Status::Low), None);
+		assert_eq!(s.update(Status::Low), Some(Status::Low));
+	}
+
+	#[test]
+	fn any() {
+		let s = Any::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Integrator::new::<Low>(NonZeroU8::new(3).unwrap()),
+		);
+		assert_eq!(s.status(), Some(Status::High));
+		assert_eq!(s.update(Status::Low), None);
+		assert_eq!(s.update(Status::Low), Some(Status::Low));
+		assert_eq!(s.update(Status::High), None);
+		assert_eq!(s.update(Status::High), Some(Status::High));
+
+		let s = Any::new(
+			LeadingEdge::new::<Low>(5),
+			Integrator::new::<Low>(NonZeroU8::new(1).unwrap()),
+		);
+		assert_eq!(s.update(Status::Low), Some(Status::Low));
+		assert_eq!(s.update(Status::High), None);
+	}
+
+	#[test]
+	fn chain() {
+		let s = Chain::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+		);
+		assert_eq!(s.update(Status::Low), Some(Status::High));
+		assert_eq!(s.update(Status::Low), None);
+		assert_eq!(s.update(Status::High), None);
+		assert_eq!(s.update(Status::Low), Some(Status::Low));
+	}
+
+	#[test]
+	fn invert() {
+		let s = Invert::new(Shifter::<u8>::new::<High>());
+		assert_eq!(s.status(), Some(Status::High));
+		for _ in 0..6 {
+			assert_eq!(s.update(Status::Low), None);
+		}
+		assert_eq!(s.update(Status::Low), Some(Status::Low));
+
+		let d = Debounced::<Low, _, _>::new(
+			Invert::new(Integrator::new::<Low>(NonZeroU8::new(1).unwrap())),
+			|| false,
+		);
+		assert_eq!(d.try_is_triggered(), Some(true));
+	}
+
+	#[test]
+	fn progress() {
+		let all = All::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Integrator::new::<Low>(NonZeroU8::new(3).unwrap()),
+		);
+		let any = Any::new(
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+			Integrator::new::<Low>(NonZeroU8::new(3).unwrap()),
+		);
+		all.update(Status::Low);
+		any.update(Status::Low);
+		assert_eq!(all.progress(Status::High), Progress::new(Status::Low, 1, 3));
+		assert_eq!(any.progress(Status::High), Progress::new(Status::Low, 1, 2));
+
+		let c = Chain::new(
+			Integrator::new::<Low>(NonZeroU8::new(1).unwrap()),
+			Integrator::new::<Low>(NonZeroU8::new(2).unwrap()),
+		);
+		c.update(Status::Low);
+		assert_eq!(c.progress(Status::High), Progress::new(Status::Low, 1, 2));
+
+		let i = Invert::new(Integrator::new::<Low>(NonZeroU8::new(2).unwrap()));
+		assert_eq!(i.update(Status::High), None);
+		assert_eq!(i.progress(Status::Low), Progress::new(Status::High, 1, 2));
+	}
+
+	#[test]
+	fn custom_progress() {
+		// reports more steps done than needed
+		struct Overdone;
+
+		impl Strategy for Overdone {
+			fn status(&self) -> Option<Status> {
+				None
+			}
+
+			fn update(&self, _status: Status) -> Option<Status> {
+				None
+			}
+
+			fn progress(&self, _settled: Status) -> Progress {
+				Progress {
+					towards: Status::High,
+					done: 5,
+					total: 3,
+				}
+			}
+		}
+
+		let all = All::new(Overdone, Integrator::new::<Low>(NonZeroU8::new(2).unwrap()));
+		let any = Any::new(Overdone, Integrator::new::<Low>(NonZeroU8::new(2).unwrap()));
+		all.update(Status::Low);
+		any.update(Status::Low);
+		assert_eq!(all.progress(Status::High), Progress::new(Status::Low, 0, 3));
+		assert_eq!(any.progress(Status::High), Progress::new(Status::Low, 1, 2));
+		assert_eq!(any.progress(Status::Low), Progress::new(Status::High, 3, 3));
+	}
+}
@@ -0,0 +1,145 @@
+use crate::{
+	active::Active,
+	strategy::{Progress, Strategy},
+	Status,
+};
+use core::cell::Cell;
+
+/// # Change-Point Detection Strategy for Debouncing
+/// Uses a cumulative sum (CUSUM) of the evidence that the input has changed
+/// level to determine if an input has stabilized.
+///
+/// While settled low, each high sample adds [`Cusum::ONE`] to the evidence,
+/// and every sample subtracts `drift` (never going below `0`). While settled
+/// high, the same is done with low samples. Once the evidence reaches
+/// `threshold`, the input has changed level and the evidence is cleared. This
+/// watches for changes in both directions, so is a two-sided detector. The
+/// detector starts as stable on the [inactive](trait@Active) value.
+///
+/// The input is always stable on its current level, so a sporadic error is
+/// never reported as unstable. Only once the evidence reaches `threshold` does
+/// it settle on the other level. Until then, the evidence is reported by
+/// [`Cusum::evidence`] and as progress towards the other level.
+///
+/// `drift / ONE` should be between the rate of errors on the input and `1`;
+/// half-way is a good default. The `threshold` trades off the rate of false
+/// detections against latency: a clean change settles after
+/// `threshold / (ONE - drift)` samples.
+///
+/// ## Comparison to [`Integrator`](crate::strategy::Integrator)
+/// - evidence of a change decays over time, so sporadic errors on a very noisy
+///   input do not accumulate into a false change
+/// - only integer arithmetic is used
+pub struct Cusum {
+	status: Cell<Status>,
+	evidence: Cell<u16>,
+	drift: u8,
+	threshold: u16,
+}
+
+impl Cusum {
+	/// The evidence added by one sample of the changed level
+	pub const ONE: u16 = 256;
+
+	/// Create a new Cusum
+	///
+	/// A `threshold` of `0` is treated as `1`.
+	pub const fn new<A: Active>(drift: u8, threshold: u16) -> Self {
+		Self {
+			status: Cell::new(A::ACTIVE_VALUE.invert()),
+			evidence: Cell::new(0),
+			drift,
+			threshold: if threshold > 0 { threshold } else { 1 },
+		}
+	}
+
+	/// The evidence accumulated so far that the input has changed level
+	pub fn evidence(&self) -> u16 {
+		self.evidence.get()
+	}
+}
+
+impl Strategy for Cusum {
+	fn status(&self) -> Option<Status> {
+		Some(self.status.get())
+	}
+
+	/// Reports the evidence towards the other level, even though the input is
+	/// stable
+	fn progress(&self, _settled: Status) -> Progress {
+		let current = self.status.get();
+		match self.evidence.get() {
+			0 => Progress::new(current, 1, 1),
+			e => Progress::new(!current, e.into(), self.threshold.into()),
+		}
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let current = self.status.get();
+		let sample = if status == current { 0 } else { Self::ONE };
+		let evidence = self
+			.evidence
+			.get()
+			.saturating_add(sample)
+			.saturating_sub(self.drift.into());
+		if evidence >= self.threshold {
+			self.status.set(!current);
+			self.evidence.set(0);
+		} else {
+			self.evidence.set(evidence);
+		}
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::active::{High, Low};
+
+	#[test]
+	fn update_progress() {
+		let c = Cusum::new::<Low>(128, 3 * 128);
+		assert_eq!(c.status(), Some(Status::High));
+		assert_eq!(c.update(Status::High), Some(Status::High));
+		assert_eq!(c.update(Status::Low), Some(Status::High));
+		assert_eq!(c.evidence(), 128);
+		assert_eq!(c.update(Status::Low), Some(Status::High));
+		assert_eq!(c.update(Status::Low), Some(Status::Low));
+		assert_eq!(c.evidence(), 0);
+		assert_eq!(c.update(Status::High), Some(Status::Low));
+		assert_eq!(c.evidence(), 128);
+		assert_eq!(c.update(Status::Low), Some(Status::Low));
+		assert_eq!(c.evidence(), 0);
+	}
+
+	#[test]
+	fn sporadic_errors() {
+		let c = Cusum::new::<High>(128, 4 * Cusum::ONE);
+		assert_eq!(c.status(), Some(Status::Low));
+		for _ in 0..100 {
+			assert_eq!(c.update(Status::High), Some(Status::Low));
+			assert_eq!(c.update(Status::Low), Some(Status::Low));
+		}
+		let settled = (0..10).position(|_| c.update(Status::High) == Some(Status::High));
+		assert_eq!(settled, Some(7));
+	}
+
+	#[test]
+	fn zero_threshold() {
+		let c = Cusum::new::<High>(0, 0);
+		assert_eq!(c.update(Status::High), Some(Status::High));
+		assert_eq!(c.update(Status::Low), Some(Status::Low));
+	}
+
+	#[test]
+	fn progress() {
+		let c = Cusum::new::<Low>(128, 3 * 128);
+		assert_eq!(c.progress(Status::High), Progress::new(Status::High, 1, 1));
+		c.update(Status::Low);
+		assert_eq!(
+			c.progress(Status::High),
+			Progress::new(Status::Low, 128, 384)
+		);
+	}
+}
@@ -0,0 +1,169 @@
+use crate::{
+	active::Active,
+	strategy::{BitHistory, Progress, Strategy},
+	Status,
+};
+use core::cell::Cell;
+
+/// # History Strategy for Debouncing
+/// Shifts each sample into a register to determine if an input has
+/// stabilized.
+///
+/// Only the bits of the history in `MASK` are considered (by default, the
+/// whole register). If they are all clear, the last samples were all low and
+/// the input is stable low. If they are all set, the input is stable high. The
+/// history starts filled with the [inactive](trait@Active) value.
+///
+/// Anywhere in-between is unstable (`None`).
+///
+/// For example, `History<u8>` settles after 8 identical samples and
+/// `History<u16, 0b1111>` settles after 4. `MASK` must have a bit set which
+/// fits in `T`, otherwise creating the strategy fails to compile.
+///
+/// ## Comparison to [`Shifter`](crate::strategy::Shifter)
+/// - records the samples themselves, so any pattern of samples can be
+///   [matched](History::matches)
+/// - settles only after consecutive identical samples: a single differing
+///   sample restarts the count
+#[repr(transparent)]
+pub struct History<T, const MASK: u64 = { u64::MAX }> {
+	reg: Cell<T>,
+}
+
+impl<T, const MASK: u64> History<T, MASK>
+where
+	T: BitHistory,
+{
+	const VALID: () = assert!(
+		MASK & (u64::MAX >> (64 - T::BITS)) != 0,
+		"the MASK of a History needs a bit set in T"
+	);
+
+	/// Create a new History
+	pub const fn new<A: Active>() -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::VALID;
+		Self {
+			reg: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
+				T::FULL
+			} else {
+				T::EMPTY
+			}),
+		}
+	}
+
+	/// The samples, with the newest sample in the lowest bit
+	pub fn history(&self) -> T {
+		self.reg.get()
+	}
+
+	/// If the bits of the history in `MASK` are the same as in `pattern`
+	///
+	/// This can detect edges. For example, with a `MASK` of `0b1111_1111`, a
+	/// `pattern` of `0b0000_1111` matches 4 high samples after 4 low samples.
+	pub fn matches(&self, pattern: T) -> bool {
+		let mask = T::truncate(MASK);
+		self.reg.get().and(mask) == pattern.and(mask)
+	}
+}
+
+impl<T, const MASK: u64> Strategy for History<T, MASK>
+where
+	T: BitHistory,
+{
+	fn status(&self) -> Option<Status> {
+		let mask = T::truncate(MASK);
+		let reg = self.reg.get().and(mask);
+		if reg == mask {
+			Some(Status::High)
+		} else if reg == T::EMPTY {
+			Some(Status::Low)
+		} else {
+			None
+		}
+	}
+
+	/// The steps are the samples within `MASK` which match the value being
+	/// settled on, even if they are not consecutive.
+	fn progress(&self, settled: Status) -> Progress {
+		let mask = T::truncate(MASK);
+		let total = mask.ones();
+		if let Some(s) = self.status() {
+			return Progress::new(s, total.into(), total.into());
+		}
+		let high = self.reg.get().and(mask).ones();
+		let towards = !settled;
+		let done = match towards {
+			Status::High => high,
+			Status::Low => total - high,
+		};
+		Progress::new(towards, done.into(), total.into())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		self.reg.set(self.reg.get().push(status.into()));
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::active::{High, Low};
+
+	#[test]
+	fn update_progress() {
+		let h = History::<u8>::new::<Low>();
+		assert_eq!(h.status(), Some(Status::High));
+		for _ in 0..7 {
+			assert_eq!(h.update(Status::Low), None);
+		}
+		assert_eq!(h.update(Status::Low), Some(Status::Low));
+		assert_eq!(h.update(Status::High), None);
+		assert_eq!(h.update(Status::Low), None);
+		assert_eq!(h.history(), 0b10);
+	}
+
+	#[test]
+	fn masked() {
+		let h = History::<u16, 0b1111>::new::<High>();
+		assert_eq!(h.status(), Some(Status::Low));
+		assert_eq!(h.update(Status::High), None);
+		assert_eq!(h.update(Status::High), None);
+		assert_eq!(h.update(Status::Low), None);
+		for _ in 0..3 {
+			assert_eq!(h.update(Status::High), None);
+		}
+		assert_eq!(h.update(Status::High), Some(Status::High));
+	}
+
+	#[test]
+	fn edges() {
+		let h = History::<u32, 0xFF>::new::<High>();
+		for _ in 0..3 {
+			h.update(Status::High);
+			assert!(!h.matches(0b0000_1111));
+		}
+		h.update(Status::High);
+		assert!(h.matches(0b0000_1111));
+		h.update(Status::High);
+		assert!(!h.matches(0b0000_1111));
+	}
+
+	#[test]
+	fn size() {
+		use core::mem::size_of;
+		assert_eq!(size_of::<History<u8>>(), 1);
+		assert_eq!(size_of::<History<u16, 0b1111>>(), 2);
+	}
+
+	#[test]
+	fn progress() {
+		let h = History::<u8>::new::<Low>();
+		assert_eq!(h.progress(Status::High), Progress::new(Status::High, 8, 8));
+		for _ in 0..3 {
+			h.update(Status::Low);
+		}
+		assert_eq!(h.progress(Status::High), Progress::new(Status::Low, 3, 8));
+	}
+}
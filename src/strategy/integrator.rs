@@ -1,4 +1,8 @@
-use crate::{active::Active, strategy::Strategy, Status};
+use crate::{
+	active::Active,
+	strategy::{Distance, Progress, Strategy},
+	Status,
+};
 use core::{cell::Cell, num::NonZeroU8};
 
 /// # Integrating Strategy for Debouncing
@@ -9,36 +13,41 @@ use core::{cell::Cell, num::NonZeroU8};
 /// [inactive](trait@Active) value.
 ///
 /// Anywhere in-between min and max is unstable (`None`).
-pub struct Integrator {
-	integrator: Cell<u8>,
-	max: NonZeroU8,
+///
+/// The counter is as wide as the [`Distance`] type `D`, so an
+/// `Integrator<NonZeroU16>` can count more than 255 samples.
+pub struct Integrator<D: Distance = NonZeroU8> {
+	integrator: Cell<D::Counter>,
+	max: D,
 }
 
-impl Integrator {
+impl<D: Distance> Integrator<D> {
 	/// Create a new Integrator
 	///
 	/// You will likely want to use [`samples`](fn@crate::samples) to compute
-	/// the distance (number of steps) between high and low inputs.
+	/// the distance (number of steps) between high and low inputs, and
+	/// [`Distance::from_samples`] to convert it.
 	///
 	/// In other words, the minimum number of times
 	/// [`update`](Integrator::update) needs to be called to toggle the
 	/// integrator's output is the `distance`.
-	pub fn new<A: Active>(distance: NonZeroU8) -> Self {
+	pub const fn new<A: Active>(distance: D) -> Self {
 		Self {
-			integrator: Cell::new(if A::ACTIVE_VALUE == Status::Low {
-				distance.get()
+			// saturated, to be clamped to the distance by the first update
+			integrator: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
+				D::MAX
 			} else {
-				0
+				D::ZERO
 			}),
 			max: distance,
 		}
 	}
 }
 
-impl Strategy for Integrator {
+impl<D: Distance> Strategy for Integrator<D> {
 	fn status(&self) -> Option<Status> {
 		let i = self.integrator.get();
-		if i == 0 {
+		if i <= D::ZERO {
 			Some(Status::Low)
 		} else if i >= self.max.get() {
 			Some(Status::High)
@@ -47,15 +56,26 @@ impl Strategy for Integrator {
 		}
 	}
 
+	fn progress(&self, settled: Status) -> Progress {
+		let max = self.max.get();
+		let i = self.integrator.get();
+		let i = if i > max { max } else { i };
+		Progress::position(settled, i.into(), max.into())
+	}
+
 	fn update(&self, status: Status) -> Option<Status> {
+		let max = self.max.get();
 		let i = self.integrator.get();
 		use Status::*;
-		match (status, i >= self.max.get()) {
-			(Low, _) => {
-				self.integrator.set(i.saturating_sub(1));
+		match (status, i >= max) {
+			(Low, true) => {
+				self.integrator.set(D::decrement(max));
+			}
+			(Low, false) => {
+				self.integrator.set(D::decrement(i));
 			}
 			(High, false) => {
-				self.integrator.set(i + 1);
+				self.integrator.set(D::increment(i));
 			}
 			(High, true) => {}
 		}
@@ -67,6 +87,7 @@ impl Strategy for Integrator {
 mod tests {
 	use super::*;
 	use crate::active::{High, Low};
+	use core::num::NonZeroU32;
 
 	#[test]
 	fn update_progress() {
@@ -91,4 +112,46 @@ mod tests {
 		let i = Integrator::new::<Low>(NonZeroU8::new(3).unwrap());
 		assert_eq!(i.status(), Some(Status::High));
 	}
+
+	#[test]
+	fn wide() {
+		use core::num::NonZeroU16;
+		let i =
+			Integrator::new::<High>(NonZeroU16::from_samples(crate::samples(50_000, 20)).unwrap());
+		for _ in 0..999 {
+			assert_eq!(i.update(Status::High), None);
+		}
+		assert_eq!(i.update(Status::High), Some(Status::High));
+		assert_eq!(NonZeroU8::from_samples(crate::samples(50_000, 20)), None);
+		assert_eq!(NonZeroU8::from_samples(0), None);
+	}
+
+	#[test]
+	fn size() {
+		use core::mem::size_of;
+		use core::num::NonZeroU16;
+		assert_eq!(size_of::<Integrator>(), 2);
+		assert_eq!(size_of::<Integrator<NonZeroU16>>(), 4);
+		assert_eq!(size_of::<Integrator<NonZeroU32>>(), 8);
+	}
+
+	#[test]
+	fn progress() {
+		let i = Integrator::new::<Low>(NonZeroU8::new(3).unwrap());
+		assert_eq!(i.progress(Status::High), Progress::new(Status::High, 3, 3));
+		i.update(Status::Low);
+		assert_eq!(i.progress(Status::High), Progress::new(Status::Low, 1, 3));
+		i.update(Status::Low);
+		assert_eq!(i.progress(Status::High), Progress::new(Status::Low, 2, 3));
+		i.update(Status::High);
+		assert_eq!(i.progress(Status::High), Progress::new(Status::Low, 1, 3));
+		i.update(Status::Low);
+		i.update(Status::Low);
+		assert!(i.progress(Status::High).is_done());
+
+		let i = Integrator::new::<Low>(NonZeroU32::new(100_000).unwrap());
+		i.update(Status::Low);
+		let p = i.progress(Status::High);
+		assert_eq!((p.towards, p.done, p.total), (Status::Low, 0, u16::MAX));
+	}
 }
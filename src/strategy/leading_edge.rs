@@ -0,0 +1,113 @@
+use crate::{active::Active, strategy::Strategy, Status};
+use core::cell::Cell;
+
+/// # Leading-Edge Strategy for Debouncing
+/// Settles on a changed input immediately, then ignores the input for a
+/// lock-out period.
+///
+/// After settling high, the next `high` updates are ignored. After settling
+/// low, the next `low` updates are ignored. The strategy starts as stable on
+/// the [inactive](trait@Active) value, and is never unstable.
+///
+/// ## Comparison to [`Integrator`](crate::strategy::Integrator)
+/// - has no latency: the first changed sample is reported
+/// - a single noisy sample is reported, so only use this on inputs which do
+///   not change without cause
+pub struct LeadingEdge {
+	status: Cell<Status>,
+	remaining: Cell<u8>,
+	high: u8,
+	low: u8,
+}
+
+impl LeadingEdge {
+	/// Create a new LeadingEdge with the same `lockout` after either change
+	pub const fn new<A: Active>(lockout: u8) -> Self {
+		Self::with_lockouts::<A>(lockout, lockout)
+	}
+
+	/// Create a new LeadingEdge with a different lock-out after settling
+	/// `high` and `low`
+	pub const fn with_lockouts<A: Active>(high: u8, low: u8) -> Self {
+		Self {
+			status: Cell::new(A::ACTIVE_VALUE.invert()),
+			remaining: Cell::new(0),
+			high,
+			low,
+		}
+	}
+
+	/// The number of updates which will still be ignored
+	pub fn remaining(&self) -> u8 {
+		self.remaining.get()
+	}
+}
+
+impl Strategy for LeadingEdge {
+	fn status(&self) -> Option<Status> {
+		Some(self.status.get())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let remaining = self.remaining.get();
+		if remaining > 0 {
+			self.remaining.set(remaining - 1);
+		} else if status != self.status.get() {
+			self.status.set(status);
+			self.remaining.set(match status {
+				Status::High => self.high,
+				Status::Low => self.low,
+			});
+		}
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::Progress,
+	};
+
+	#[test]
+	fn update_progress() {
+		let e = LeadingEdge::new::<Low>(2);
+		assert_eq!(e.status(), Some(Status::High));
+		assert_eq!(e.update(Status::High), Some(Status::High));
+		assert_eq!(e.update(Status::Low), Some(Status::Low));
+		assert_eq!(e.update(Status::High), Some(Status::Low));
+		assert_eq!(e.update(Status::High), Some(Status::Low));
+		assert_eq!(e.update(Status::High), Some(Status::High));
+		assert_eq!(e.remaining(), 2);
+	}
+
+	#[test]
+	fn asymmetric() {
+		let e = LeadingEdge::with_lockouts::<High>(3, 0);
+		assert_eq!(e.status(), Some(Status::Low));
+		assert_eq!(e.update(Status::High), Some(Status::High));
+		assert_eq!(e.update(Status::Low), Some(Status::High));
+		assert_eq!(e.update(Status::Low), Some(Status::High));
+		assert_eq!(e.update(Status::Low), Some(Status::High));
+		assert_eq!(e.update(Status::Low), Some(Status::Low));
+		assert_eq!(e.update(Status::High), Some(Status::High));
+		assert_eq!(e.remaining(), 3);
+	}
+
+	#[test]
+	fn debounced() {
+		use crate::Debounced;
+		let d = Debounced::<High, _, _>::new(LeadingEdge::new::<High>(4), || true);
+		assert_eq!(d.try_is_triggered(), Some(true));
+	}
+
+	#[test]
+	fn progress() {
+		let e = LeadingEdge::new::<Low>(2);
+		assert_eq!(e.progress(Status::High), Progress::new(Status::High, 1, 1));
+		e.update(Status::Low);
+		assert!(e.progress(Status::High).is_done());
+	}
+}
@@ -0,0 +1,148 @@
+use crate::{
+	active::Active,
+	strategy::{Progress, Strategy},
+	Status,
+};
+use core::cell::Cell;
+
+/// # Low-Pass Filter Strategy for Debouncing
+/// Uses a first-order IIR filter (an exponential moving average) of the input
+/// to determine if an input has stabilized.
+///
+/// Each sample moves the filtered value `1 / 2.pow(shift)` of the way towards
+/// the input, where a low input is `0` and a high input is [`u16::MAX`]. If
+/// the filtered value is at or below `low`, the input is stable low. If it is
+/// at or above `high`, the input is stable high. The filter starts as stable
+/// on the [inactive](trait@Active) value.
+///
+/// Anywhere in-between the thresholds is unstable (`None`).
+///
+/// Only integer arithmetic is used.
+///
+/// ## Comparison to [`Integrator`](crate::strategy::Integrator)
+/// - periodic interference is smoothed out, rather than moving the integrator
+///   back and forth
+/// - the time to settle depends on how long the input was stable
+pub struct LowPass {
+	value: Cell<u16>,
+	shift: u8,
+	low: u16,
+	high: u16,
+}
+
+impl LowPass {
+	/// Create a new LowPass
+	///
+	/// A `shift` greater than 16 is treated as 16. The `low` threshold should
+	/// be less than the `high` threshold.
+	pub const fn new<A: Active>(shift: u8, low: u16, high: u16) -> Self {
+		Self {
+			value: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
+				u16::MAX
+			} else {
+				0
+			}),
+			shift: if shift < 16 { shift } else { 16 },
+			low,
+			high,
+		}
+	}
+
+	/// The current filtered value
+	pub fn value(&self) -> u16 {
+		self.value.get()
+	}
+
+	/// Rounds away from zero so that the filter reaches the input
+	fn step(&self, difference: u16) -> u16 {
+		((u32::from(difference) + (1 << self.shift) - 1) >> self.shift) as u16
+	}
+}
+
+impl Strategy for LowPass {
+	fn status(&self) -> Option<Status> {
+		let v = self.value.get();
+		if v >= self.high {
+			Some(Status::High)
+		} else if v <= self.low {
+			Some(Status::Low)
+		} else {
+			None
+		}
+	}
+
+	fn progress(&self, settled: Status) -> Progress {
+		let above_low = self.value.get().saturating_sub(self.low);
+		let total = self.high.saturating_sub(self.low);
+		Progress::position(settled, above_low.into(), total.into())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let v = self.value.get();
+		self.value.set(match status {
+			Status::Low => v - self.step(v),
+			Status::High => v + self.step(u16::MAX - v),
+		});
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::active::{High, Low};
+
+	const LOW: u16 = 0x4000;
+	const HIGH: u16 = 0xC000;
+
+	#[test]
+	fn update_progress() {
+		let f = LowPass::new::<Low>(1, LOW, HIGH);
+		assert_eq!(f.status(), Some(Status::High));
+		assert_eq!(f.update(Status::Low), None);
+		assert_eq!(f.value(), 0x7FFF);
+		assert_eq!(f.update(Status::Low), Some(Status::Low));
+		assert_eq!(f.update(Status::High), None);
+		assert_eq!(f.update(Status::High), Some(Status::High));
+	}
+
+	#[test]
+	fn reaches_input() {
+		let f = LowPass::new::<High>(4, LOW, u16::MAX);
+		assert_eq!(f.status(), Some(Status::Low));
+		while f.update(Status::High) != Some(Status::High) {}
+		assert_eq!(f.value(), u16::MAX);
+		while f.update(Status::Low) != Some(Status::Low) {}
+		while f.value() > 0 {
+			assert_eq!(f.update(Status::Low), Some(Status::Low));
+		}
+
+		let f = LowPass::new::<High>(100, 0, u16::MAX);
+		for _ in 0..u16::MAX - 1 {
+			assert_eq!(f.update(Status::High), None);
+		}
+		assert_eq!(f.update(Status::High), Some(Status::High));
+	}
+
+	#[test]
+	fn smooths_interference() {
+		let f = LowPass::new::<High>(3, LOW, HIGH);
+		for _ in 0..50 {
+			assert_ne!(f.update(Status::High), Some(Status::High));
+			assert_ne!(f.update(Status::Low), Some(Status::High));
+		}
+		assert_eq!(f.status(), None);
+	}
+
+	#[test]
+	fn progress() {
+		let f = LowPass::new::<Low>(1, LOW, HIGH);
+		f.update(Status::Low);
+		assert_eq!(
+			f.progress(Status::High),
+			Progress::new(Status::Low, 0x4001, 0x8000)
+		);
+		f.update(Status::Low);
+		assert!(f.progress(Status::High).is_done());
+	}
+}
@@ -0,0 +1,145 @@
+use crate::{
+	active::Active,
+	strategy::{BitHistory, Progress, Strategy},
+	Status,
+};
+use core::cell::Cell;
+
+/// # Majority-Vote Strategy for Debouncing
+/// Keeps a window of the last `M` samples, and votes on the value of the
+/// input.
+///
+/// If at least `N` samples in the window are low, the input is stable low. If
+/// at least `N` samples are high, the input is stable high. The window starts
+/// filled with the [inactive](trait@Active) value.
+///
+/// Anywhere in-between is unstable (`None`).
+///
+/// `N` must be more than half of `M` (so that both values can not win a vote),
+/// and `M` must fit in `T`. Otherwise, creating the strategy fails to compile.
+///
+/// ## Comparison to [`Integrator`](crate::strategy::Integrator)
+/// - a short burst of noise is outvoted, rather than undoing the integration
+///   built up by a long run of samples
+/// - is more space efficient since `N` and `M` are constants, not stored
+///   values
+#[repr(transparent)]
+pub struct Majority<T, const N: u8, const M: u8> {
+	window: Cell<T>,
+}
+
+impl<T, const N: u8, const M: u8> Majority<T, N, M>
+where
+	T: BitHistory,
+{
+	const VALID: () = assert!(
+		M <= T::BITS && N <= M && N > M / 2,
+		"a majority needs M <= T::BITS and M / 2 < N <= M"
+	);
+
+	/// Create a new Majority
+	pub const fn new<A: Active>() -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::VALID;
+		Self {
+			window: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
+				T::FULL
+			} else {
+				T::EMPTY
+			}),
+		}
+	}
+}
+
+impl<T, const N: u8, const M: u8> Strategy for Majority<T, N, M>
+where
+	T: BitHistory,
+{
+	fn status(&self) -> Option<Status> {
+		let high = self.window.get().and(T::mask(M)).ones();
+		if high >= N {
+			Some(Status::High)
+		} else if M - high >= N {
+			Some(Status::Low)
+		} else {
+			None
+		}
+	}
+
+	fn progress(&self, settled: Status) -> Progress {
+		if let Some(s) = self.status() {
+			return Progress::new(s, N.into(), N.into());
+		}
+		let high = self.window.get().and(T::mask(M)).ones();
+		let towards = !settled;
+		let done = match towards {
+			Status::High => high,
+			Status::Low => M - high,
+		};
+		Progress::new(towards, done.into(), N.into())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let window = self.window.get().push(status.into());
+		self.window.set(window.and(T::mask(M)));
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::active::{High, Low};
+
+	#[test]
+	fn update_progress() {
+		let m = Majority::<u8, 3, 5>::new::<Low>();
+		assert_eq!(m.status(), Some(Status::High));
+		assert_eq!(m.update(Status::Low), Some(Status::High));
+		assert_eq!(m.update(Status::Low), Some(Status::High));
+		assert_eq!(m.update(Status::Low), Some(Status::Low));
+		// impulses are outvoted
+		assert_eq!(m.update(Status::High), Some(Status::Low));
+		assert_eq!(m.update(Status::Low), Some(Status::Low));
+		assert_eq!(m.update(Status::High), Some(Status::Low));
+		assert_eq!(m.update(Status::High), Some(Status::High));
+	}
+
+	#[test]
+	fn undecided() {
+		let m = Majority::<u16, 4, 5>::new::<High>();
+		assert_eq!(m.status(), Some(Status::Low));
+		assert_eq!(m.update(Status::High), Some(Status::Low));
+		assert_eq!(m.update(Status::High), None);
+		assert_eq!(m.update(Status::High), None);
+		assert_eq!(m.update(Status::High), Some(Status::High));
+		assert_eq!(m.update(Status::Low), Some(Status::High));
+		assert_eq!(m.update(Status::Low), None);
+	}
+
+	#[test]
+	fn whole_register() {
+		let m = Majority::<u8, 8, 8>::new::<Low>();
+		assert_eq!(m.status(), Some(Status::High));
+		for _ in 0..7 {
+			assert_eq!(m.update(Status::Low), None);
+		}
+		assert_eq!(m.update(Status::Low), Some(Status::Low));
+	}
+
+	#[test]
+	fn size() {
+		use core::mem::size_of;
+		assert_eq!(size_of::<Majority<u8, 3, 5>>(), 1);
+		assert_eq!(size_of::<Majority<u16, 8, 15>>(), 2);
+	}
+
+	#[test]
+	fn progress() {
+		let m = Majority::<u8, 4, 5>::new::<Low>();
+		assert_eq!(m.progress(Status::High), Progress::new(Status::High, 4, 4));
+		m.update(Status::Low);
+		assert_eq!(m.update(Status::Low), None);
+		assert_eq!(m.progress(Status::High), Progress::new(Status::Low, 2, 4));
+	}
+}
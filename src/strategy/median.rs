@@ -0,0 +1,139 @@
+use crate::{
+	active::Active,
+	strategy::{BitHistory, Progress, Strategy},
+	Status,
+};
+use core::cell::Cell;
+
+/// # Median Filter Strategy for Debouncing
+/// Takes the median of the last `N` samples to reject impulses, and waits for
+/// the median to be stable to determine if an input has stabilized.
+///
+/// If the median has been low for the last `HOLD` samples, the input is stable
+/// low. If it has been high, the input is stable high. The history starts
+/// filled with the [inactive](trait@Active) value.
+///
+/// Anywhere in-between is unstable (`None`).
+///
+/// The last `N + HOLD - 1` samples are stored in the bits of `T`, so a median
+/// of 15 fits in a `u16`. `N` must be odd, `HOLD` must not be `0`, and the
+/// samples must fit in `T`. Otherwise, creating the strategy fails to compile.
+///
+/// ## Comparison to [`Majority`](crate::strategy::Majority)
+/// - the median of binary samples is the majority of an odd window, which is
+///   then held for `HOLD` samples like an
+///   [`Integrator`](crate::strategy::Integrator)
+/// - is never undecided if `HOLD` is `1`
+#[repr(transparent)]
+pub struct Median<T, const N: u8, const HOLD: u8> {
+	history: Cell<T>,
+}
+
+impl<T, const N: u8, const HOLD: u8> Median<T, N, HOLD>
+where
+	T: BitHistory,
+{
+	const VALID: () = assert!(
+		N % 2 == 1 && HOLD > 0 && N as u16 + HOLD as u16 - 1 <= T::BITS as u16,
+		"a median needs an odd N, a non-zero HOLD, and N + HOLD - 1 <= T::BITS"
+	);
+
+	/// Create a new Median
+	pub const fn new<A: Active>() -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::VALID;
+		Self {
+			history: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
+				T::FULL
+			} else {
+				T::EMPTY
+			}),
+		}
+	}
+
+	/// The median of the `N` samples before the last `age` samples
+	fn median(&self, age: u8) -> Status {
+		let history = self.history.get();
+		let high = history.and(T::mask(age + N)).ones() - history.and(T::mask(age)).ones();
+		(high > N / 2).into()
+	}
+}
+
+impl<T, const N: u8, const HOLD: u8> Strategy for Median<T, N, HOLD>
+where
+	T: BitHistory,
+{
+	fn status(&self) -> Option<Status> {
+		let median = self.median(0);
+		if (1..HOLD).all(|age| self.median(age) == median) {
+			Some(median)
+		} else {
+			None
+		}
+	}
+
+	/// The steps are the latest medians which are the value being settled on.
+	fn progress(&self, settled: Status) -> Progress {
+		if let Some(s) = self.status() {
+			return Progress::new(s, HOLD.into(), HOLD.into());
+		}
+		let towards = !settled;
+		let done = (0..HOLD)
+			.take_while(|&age| self.median(age) == towards)
+			.count();
+		Progress::new(towards, done as u64, HOLD.into())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let history = self.history.get().push(status.into());
+		self.history.set(history.and(T::mask(N + HOLD - 1)));
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::active::{High, Low};
+
+	#[test]
+	fn update_progress() {
+		let m = Median::<u8, 3, 3>::new::<Low>();
+		assert_eq!(m.status(), Some(Status::High));
+		assert_eq!(m.update(Status::Low), Some(Status::High));
+		assert_eq!(m.update(Status::Low), None);
+		assert_eq!(m.update(Status::Low), None);
+		assert_eq!(m.update(Status::Low), Some(Status::Low));
+		// impulses are rejected
+		assert_eq!(m.update(Status::High), Some(Status::Low));
+		assert_eq!(m.update(Status::Low), Some(Status::Low));
+	}
+
+	#[test]
+	fn plain_median() {
+		let m = Median::<u16, 15, 1>::new::<High>();
+		assert_eq!(m.status(), Some(Status::Low));
+		for _ in 0..7 {
+			assert_eq!(m.update(Status::High), Some(Status::Low));
+		}
+		assert_eq!(m.update(Status::High), Some(Status::High));
+	}
+
+	#[test]
+	fn size() {
+		use core::mem::size_of;
+		assert_eq!(size_of::<Median<u16, 15, 1>>(), 2);
+		assert_eq!(size_of::<Median<u8, 5, 4>>(), 1);
+	}
+
+	#[test]
+	fn progress() {
+		let m = Median::<u8, 3, 3>::new::<Low>();
+		m.update(Status::Low);
+		assert_eq!(m.progress(Status::High), Progress::new(Status::High, 3, 3));
+		m.update(Status::Low);
+		assert_eq!(m.progress(Status::High), Progress::new(Status::Low, 1, 3));
+		m.update(Status::Low);
+		assert_eq!(m.progress(Status::High), Progress::new(Status::Low, 2, 3));
+	}
+}
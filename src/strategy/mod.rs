@@ -7,14 +7,37 @@
 //! a [`Debouncer`](crate::Debounced).
 
 use crate::Status;
-use core::ops::{Shl, Shr};
+use core::{
+	num::{NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8},
+	ops::{Shl, Shr},
+};
 
+mod adaptive;
+pub use adaptive::Adaptive;
+mod combinator;
+pub use combinator::{All, Any, Chain, Invert};
+mod cusum;
+pub use cusum::Cusum;
+mod history;
+pub use history::History;
 mod integrator;
 pub use integrator::Integrator;
+mod leading_edge;
+pub use leading_edge::LeadingEdge;
+mod low_pass;
+pub use low_pass::LowPass;
+mod majority;
+pub use majority::Majority;
+mod median;
+pub use median::Median;
+mod packed;
+pub use packed::PackedIntegrator;
 mod shift;
 pub use shift::Shifter;
 mod shift_const;
-pub use shift_const::IntegrandShifter;
+pub use shift_const::{
+	Integrand, Integrand16, Integrand32, IntegrandShifter, IntegrandShifter16, IntegrandShifter32,
+};
 
 /// # Defining the Debouncing Algorithm
 /// The strategy needs to do everything to debounce the input, but it should not
@@ -33,6 +56,86 @@ pub trait Strategy {
 	///
 	/// Returns [`Strategy::status`].
 	fn update(&self, status: Status) -> Option<Status>;
+
+	/// How far the debouncer is from settling, given the value it last
+	/// `settled` on (which it does not store).
+	///
+	/// If the debouncer is stable, it is done settling towards the stable
+	/// value. Otherwise, it is settling towards the opposite of `settled`. By
+	/// default, no progress is reported until the debouncer is stable.
+	fn progress(&self, settled: Status) -> Progress {
+		match self.status() {
+			Some(s) => Progress::new(s, 1, 1),
+			None => Progress::new(!settled, 0, 1),
+		}
+	}
+}
+
+/// # Progress Towards Settling
+/// A [`Strategy`] has settled on `towards` when `done` reaches `total` (eg.
+/// for a "hold to confirm" progress bar).
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Progress {
+	/// The value being settled on
+	pub towards: Status,
+	/// The steps taken, at most `total`
+	pub done: u16,
+	/// The steps needed to settle, at least `1`
+	pub total: u16,
+}
+
+impl Progress {
+	/// Create a new Progress
+	///
+	/// If `total` does not fit in a `u16`, both are scaled down to fit. `done`
+	/// is limited to `total`, and a `total` of `0` is treated as `1`.
+	pub fn new(towards: Status, done: u64, total: u64) -> Self {
+		let total = total.max(1);
+		let done = done.min(total);
+		let max = u64::from(u16::MAX);
+		let (done, total) = if total > max {
+			(
+				(u128::from(done) * u128::from(max) / u128::from(total)) as u64,
+				max,
+			)
+		} else {
+			(done, total)
+		};
+		Self {
+			towards,
+			done: done as u16,
+			total: total as u16,
+		}
+	}
+
+	/// The progress of a counter at `position` out of `total`, which is
+	/// stable low at `0` and stable high at `total`
+	pub(crate) fn position(settled: Status, position: u64, total: u64) -> Self {
+		if position == 0 {
+			Self::new(Status::Low, total, total)
+		} else if position >= total {
+			Self::new(Status::High, total, total)
+		} else if settled == Status::High {
+			Self::new(Status::Low, total - position, total)
+		} else {
+			Self::new(Status::High, position, total)
+		}
+	}
+
+	/// The steps taken towards `status`: if settling the other way, the steps
+	/// which remain (none, if `done` is past `total`)
+	pub(crate) fn done_towards(&self, status: Status) -> u16 {
+		if self.towards == status {
+			self.done
+		} else {
+			self.total.saturating_sub(self.done)
+		}
+	}
+
+	/// If `done` has reached `total`
+	pub fn is_done(&self) -> bool {
+		self.done >= self.total
+	}
 }
 
 /// # Types Which Are Like Integers
@@ -51,6 +154,23 @@ pub trait NumericType: Shl<u8, Output = Self> + Shr<u8, Output = Self> + Copy +
 	const MAX: Self;
 	/// The type's minimum value (no value can be smaller)
 	const MIN: Self;
+
+	/// The number of `<< 1` from [`MIN`](NumericType::MIN) to reach the value
+	///
+	/// By default, this is counted one shift at a time.
+	fn steps(self) -> u64 {
+		let mut reg = Self::MIN;
+		let mut steps = 0;
+		while reg < self {
+			let next = reg << 1;
+			if next <= reg {
+				break;
+			}
+			reg = next;
+			steps += 1;
+		}
+		steps
+	}
 }
 
 macro_rules! impl_numeric_type {
@@ -59,9 +179,133 @@ macro_rules! impl_numeric_type {
             impl NumericType for $type {
                 const MAX: Self = 1 << $max;
                 const MIN: Self = 1;
+
+                fn steps(self) -> u64 {
+                    self.checked_ilog2().unwrap_or(0).into()
+                }
             }
         )+
     };
 }
 
 impl_numeric_type!(u8, 7 u16, 15 u32, 31 u64, 63);
+
+/// # Non-Zero Integers Which Count Samples
+///
+/// The distance between stable low and stable high of an
+/// [`Integrator`], which also decides how wide its counter is.
+pub trait Distance: Copy {
+	/// The counter, which can count up to any distance
+	type Counter: Copy + PartialOrd + Into<u64>;
+	/// The counter's minimum value
+	const ZERO: Self::Counter;
+	/// The counter's maximum value
+	const MAX: Self::Counter;
+
+	/// The distance as a counter
+	fn get(self) -> Self::Counter;
+	/// The distance needed to count `samples`, if it is not zero and fits
+	///
+	/// Use this with [`samples`](fn@crate::samples) rather than casting,
+	/// which can silently truncate.
+	fn from_samples(samples: usize) -> Option<Self>;
+	/// The counter plus one, saturating
+	fn increment(counter: Self::Counter) -> Self::Counter;
+	/// The counter minus one, saturating
+	fn decrement(counter: Self::Counter) -> Self::Counter;
+}
+
+macro_rules! impl_distance {
+    ($($type:ty, $counter:ty)+) => {
+        $(
+            impl Distance for $type {
+                type Counter = $counter;
+                const ZERO: Self::Counter = 0;
+                const MAX: Self::Counter = <$counter>::MAX;
+
+                fn get(self) -> Self::Counter {
+                    <$type>::get(self)
+                }
+
+                fn from_samples(samples: usize) -> Option<Self> {
+                    <$counter as core::convert::TryFrom<usize>>::try_from(samples)
+                        .ok()
+                        .and_then(<$type>::new)
+                }
+
+                fn increment(counter: Self::Counter) -> Self::Counter {
+                    counter.saturating_add(1)
+                }
+
+                fn decrement(counter: Self::Counter) -> Self::Counter {
+                    counter.saturating_sub(1)
+                }
+            }
+        )+
+    };
+}
+
+impl_distance!(NonZeroU8, u8 NonZeroU16, u16 NonZeroU32, u32 NonZeroU64, u64);
+
+/// # Types Which Store a History of Samples
+///
+/// Each bit holds one sample, with the newest sample in the lowest bit, so
+/// that history-based debouncing is possible.
+pub trait BitHistory: Copy + PartialEq {
+	/// The number of samples which can be stored
+	const BITS: u8;
+	/// No bits set
+	const EMPTY: Self;
+	/// Every bit set
+	const FULL: Self;
+
+	/// The lowest `width` bits set
+	fn mask(width: u8) -> Self;
+	/// The lowest bits of `bits`, dropping those which do not fit
+	fn truncate(bits: u64) -> Self;
+	/// Shifts the history towards the highest bit, adding `sample` as the
+	/// lowest bit and dropping the highest bit
+	fn push(self, sample: bool) -> Self;
+	/// The bits set in both `self` and `other`
+	fn and(self, other: Self) -> Self;
+	/// The number of bits set
+	fn ones(self) -> u8;
+}
+
+macro_rules! impl_bit_history {
+    ($($type:ty)+) => {
+        $(
+            impl BitHistory for $type {
+                const BITS: u8 = <$type>::BITS as u8;
+                const EMPTY: Self = 0;
+                const FULL: Self = <$type>::MAX;
+
+                fn mask(width: u8) -> Self {
+                    if width >= <Self as BitHistory>::BITS {
+                        <$type>::MAX
+                    } else {
+                        (1 << width) - 1
+                    }
+                }
+
+                fn truncate(bits: u64) -> Self {
+                    bits as $type
+                }
+
+                fn push(self, sample: bool) -> Self {
+                    self << 1 | sample as $type
+                }
+
+                fn and(self, other: Self) -> Self {
+                    self & other
+                }
+
+                fn ones(self) -> u8 {
+                    self.count_ones() as u8
+                }
+            }
+        )+
+    };
+}
+
+impl_bit_history!(u8 u16 u32 u64);
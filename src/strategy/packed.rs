@@ -0,0 +1,126 @@
+use crate::{
+	active::Active,
+	strategy::{Progress, Strategy},
+	Status,
+};
+use core::cell::Cell;
+
+/// The bit of the state storing the last settled value
+const SETTLED: u8 = 1 << 7;
+/// The bits of the state storing the counter
+const COUNTER: u8 = !SETTLED;
+
+/// # Integrating Strategy Storing its Settled Value
+/// Counts like an [`IntegrandShifter<N>`](super::IntegrandShifter), but stores
+/// the value it last settled on in the top bit of its counter, so that both
+/// fit in one byte. Unlike other strategies, it does store the most recent
+/// state ([`Self::last_settled`]), which is used by
+/// [`PackedDebounced`](crate::PackedDebounced).
+///
+/// The counter counts up (for a high sample) or down (for a low sample)
+/// between 0 and `N`. At 0, the input is stable low, and at `N`, it is stable
+/// high. `N` must be at most 127 (so that the counter fits in 7 bits),
+/// otherwise creating the strategy fails to compile. The counter starts as
+/// stable on the [inactive](trait@Active) value.
+pub struct PackedIntegrator<const N: u8> {
+	state: Cell<u8>,
+}
+
+impl<const N: u8> PackedIntegrator<N> {
+	const VALID: () = assert!(N <= COUNTER, "a PackedIntegrator counts to at most 127");
+
+	/// Create a new PackedIntegrator
+	pub const fn new<A: Active>() -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::VALID;
+		Self {
+			state: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
+				SETTLED | N
+			} else {
+				0
+			}),
+		}
+	}
+
+	/// The value the counter last settled on
+	pub fn last_settled(&self) -> Status {
+		(self.state.get() & SETTLED != 0).into()
+	}
+
+	fn count(&self) -> u8 {
+		self.state.get() & COUNTER
+	}
+}
+
+impl<const N: u8> Strategy for PackedIntegrator<N> {
+	fn status(&self) -> Option<Status> {
+		match self.count() {
+			0 => Some(Status::Low),
+			count if count >= N => Some(Status::High),
+			_ => None,
+		}
+	}
+
+	/// Ignores `settled`, since the value it last settled on is stored
+	fn progress(&self, _settled: Status) -> Progress {
+		Progress::position(self.last_settled(), self.count().into(), N.into())
+	}
+
+	fn update(&self, status: Status) -> Option<Status> {
+		let count = match status {
+			Status::Low => self.count().saturating_sub(1),
+			Status::High => self.count().saturating_add(1).min(N),
+		};
+		let settled = if count == 0 {
+			0
+		} else if count >= N {
+			SETTLED
+		} else {
+			self.state.get() & SETTLED
+		};
+		self.state.set(settled | count);
+		self.status()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		active::{High, Low},
+		strategy::IntegrandShifter,
+	};
+
+	fn compare<const N: u8>() {
+		let samples = [
+			false, false, true, false, false, false, true, true, true, true,
+		];
+		let p = PackedIntegrator::<N>::new::<Low>();
+		let s = IntegrandShifter::<N>::new::<Low>();
+		for &sample in &samples {
+			assert_eq!(p.update(sample.into()), s.update(sample.into()));
+		}
+	}
+
+	#[test]
+	fn matches_integrand_shifter() {
+		compare::<0>();
+		compare::<1>();
+		compare::<3>();
+		compare::<127>();
+	}
+
+	#[test]
+	fn stores_settled() {
+		let p = PackedIntegrator::<3>::new::<High>();
+		assert_eq!(p.last_settled(), Status::Low);
+		assert_eq!(p.update(Status::High), None);
+		assert_eq!(p.update(Status::High), None);
+		assert_eq!(p.last_settled(), Status::Low);
+		assert_eq!(p.update(Status::High), Some(Status::High));
+		assert_eq!(p.update(Status::Low), None);
+		assert_eq!(p.last_settled(), Status::High);
+		assert_eq!(p.progress(Status::Low), Progress::new(Status::Low, 1, 3));
+		assert_eq!(core::mem::size_of_val(&p), 1);
+	}
+}
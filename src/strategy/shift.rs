@@ -1,6 +1,6 @@
 use crate::{
 	active::Active,
-	strategy::{NumericType, Strategy},
+	strategy::{NumericType, Progress, Strategy},
 	Status,
 };
 use core::cell::Cell;
@@ -28,9 +28,9 @@ where
 	T: NumericType,
 {
 	/// Create a new Shifter
-	pub fn new<A: Active>() -> Self {
+	pub const fn new<A: Active>() -> Self {
 		Self {
-			reg: Cell::new(if A::ACTIVE_VALUE == Status::Low {
+			reg: Cell::new(if matches!(A::ACTIVE_VALUE, Status::Low) {
 				T::MAX
 			} else {
 				T::MIN
@@ -54,6 +54,10 @@ where
 		}
 	}
 
+	fn progress(&self, settled: Status) -> Progress {
+		Progress::position(settled, self.reg.get().steps(), T::MAX.steps())
+	}
+
 	fn update(&self, status: Status) -> Option<Status> {
 		let reg = self.reg.get();
 		match status {
@@ -107,4 +111,47 @@ mod tests {
 		let i = Shifter::<u8>::new::<Low>();
 		assert_eq!(i.status(), Some(Status::High));
 	}
+
+	#[test]
+	fn progress() {
+		let i = Shifter::<u8>::new::<Low>();
+		assert_eq!(i.progress(Status::High), Progress::new(Status::High, 7, 7));
+		i.update(Status::Low);
+		i.update(Status::Low);
+		assert_eq!(i.progress(Status::High), Progress::new(Status::Low, 2, 7));
+	}
+
+	#[test]
+	fn default_steps() {
+		use core::ops::{Shl, Shr};
+
+		#[derive(Copy, Clone, PartialEq, PartialOrd)]
+		struct Wrapped(u8);
+
+		impl Shl<u8> for Wrapped {
+			type Output = Self;
+
+			fn shl(self, rhs: u8) -> Self {
+				Self(self.0 << rhs)
+			}
+		}
+
+		impl Shr<u8> for Wrapped {
+			type Output = Self;
+
+			fn shr(self, rhs: u8) -> Self {
+				Self(self.0 >> rhs)
+			}
+		}
+
+		impl NumericType for Wrapped {
+			const MAX: Self = Self(1 << 4);
+			const MIN: Self = Self(1);
+		}
+
+		assert_eq!(Wrapped::MAX.steps(), 4);
+		assert_eq!(Wrapped(4).steps(), 2);
+		assert_eq!(Wrapped(u8::MAX).steps(), 7);
+		assert_eq!(Wrapped::MAX.steps(), u8::MAX.steps() - 3);
+	}
 }
@@ -3,34 +3,60 @@ use core::ops::{Shl, Shr};
 
 /// A Strategy
 pub type IntegrandShifter<const N: u8> = Shifter<Integrand<N>>;
+/// A Strategy counting up to [`u16::MAX`]
+pub type IntegrandShifter16<const N: u16> = Shifter<Integrand16<N>>;
+/// A Strategy counting up to [`u32::MAX`]
+pub type IntegrandShifter32<const N: u32> = Shifter<Integrand32<N>>;
 
-/// Implements an Integrotor for use in the Shifter strategy
-#[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
-#[repr(transparent)]
-pub struct Integrand<const N: u8>(u8);
+macro_rules! integrand {
+    ($($(#[$doc:meta])* $name:ident, $type:ty)+) => {
+        $(
+            $(#[$doc])*
+            #[derive(Debug, Copy, Clone, PartialEq, PartialOrd)]
+            #[repr(transparent)]
+            pub struct $name<const N: $type>($type);
 
-impl<const N: u8> Shl<u8> for Integrand<N> {
-	type Output = Self;
+            impl<const N: $type> Shl<u8> for $name<N> {
+                type Output = Self;
 
-	fn shl(self, rhs: u8) -> Self::Output {
-		Self(self.0.saturating_add(rhs))
-	}
-}
+                fn shl(self, rhs: u8) -> Self::Output {
+                    Self(self.0.saturating_add(rhs.into()))
+                }
+            }
 
-impl<const N: u8> Shr<u8> for Integrand<N> {
-	type Output = Self;
+            impl<const N: $type> Shr<u8> for $name<N> {
+                type Output = Self;
 
-	fn shr(self, rhs: u8) -> Self::Output {
-		Self(self.0.saturating_sub(rhs))
-	}
-}
+                fn shr(self, rhs: u8) -> Self::Output {
+                    Self(self.0.saturating_sub(rhs.into()))
+                }
+            }
+
+            impl<const N: $type> NumericType for $name<N> {
+                const MAX: Self = Self(N);
+                const MIN: Self = Self(0);
 
-impl<const N: u8> NumericType for Integrand<N> {
-	const MAX: Self = Self(N);
-	const MIN: Self = Self(0);
+                fn steps(self) -> u64 {
+                    self.0.into()
+                }
+            }
+        )+
+    };
 }
 
+integrand!(
+	/// Implements an Integrotor for use in the Shifter strategy
+	Integrand, u8
+	/// Implements an Integrator counting up to [`u16::MAX`] for use in the
+	/// Shifter strategy
+	Integrand16, u16
+	/// Implements an Integrator counting up to [`u32::MAX`] for use in the
+	/// Shifter strategy
+	Integrand32, u32
+);
+
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
 	use crate::active::{High, Low};
 	use crate::Debounced;
@@ -68,6 +94,25 @@ mod tests {
 		assert_eq!(sz, 2);
 	}
 
+	#[test]
+	fn wide() {
+		use crate::strategy::{IntegrandShifter16, IntegrandShifter32, Progress};
+		use crate::Status;
+		use core::mem::size_of;
+		let d = Debounced::<Low, IntegrandShifter16<1000>, _>::with(|| false);
+		for _ in 0..999 {
+			assert_eq!(d.try_is_triggered(), None);
+		}
+		assert_eq!(d.try_is_triggered(), Some(true));
+		d.try_get();
+		assert_eq!(d.progress(), Progress::new(Status::Low, 1000, 1000));
+		let d = Debounced::<Low, IntegrandShifter16<1000>, _>::with(|| false);
+		d.try_get();
+		assert_eq!(d.progress(), Progress::new(Status::Low, 1, 1000));
+		assert_eq!(size_of::<IntegrandShifter16<1000>>(), 2);
+		assert_eq!(size_of::<IntegrandShifter32<100_000>>(), 4);
+	}
+
 	#[test]
 	fn zero() {
 		let d = DbShfInt::<Low, _, 0>::with(|| false);
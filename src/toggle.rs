@@ -0,0 +1,199 @@
+use crate::{
+	active::Active,
+	strategy::{Progress, Strategy},
+	Debounced, InputSource, Status,
+};
+use core::cell::Cell;
+
+/// # Push-On/Push-Off Input
+/// Flips a stored state each time the [`Debounced`] input settles on its
+/// [active](trait@Active) value. Releasing the input does nothing.
+///
+/// Since only a change of the settled value flips the state, a bouncing input
+/// can not flip it twice. The state starts cleared (`false`).
+///
+/// Optionally, the state will not flip again until a minimum number of updates
+/// have passed since it last flipped.
+pub struct Toggle<A, S, F> {
+	debounced: Debounced<A, S, F>,
+	state: Cell<bool>,
+	interval: u16,
+	elapsed: Cell<u16>,
+}
+
+impl<A, S, F> Toggle<A, S, F> {
+	/// Create a new Toggle, starting cleared
+	pub const fn new(debounced: Debounced<A, S, F>) -> Self {
+		Self::with_interval(debounced, 0)
+	}
+
+	/// Create a new Toggle which flips at most once per `interval` updates
+	pub const fn with_interval(debounced: Debounced<A, S, F>, interval: u16) -> Self {
+		Self {
+			debounced,
+			state: Cell::new(false),
+			interval,
+			elapsed: Cell::new(interval),
+		}
+	}
+
+	/// The state, without updating the input
+	pub fn is_set(&self) -> bool {
+		self.state.get()
+	}
+
+	/// Sets the state, as if it flipped on
+	pub fn set(&self) {
+		self.state.set(true);
+	}
+
+	/// Clears the state, as if it flipped off
+	pub fn clear(&self) {
+		self.state.set(false);
+	}
+}
+
+impl<A, S, F> Toggle<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: InputSource,
+{
+	/// The last settled value of the input, without updating it.
+	pub fn last_settled(&self) -> Status {
+		self.debounced.last_settled()
+	}
+
+	/// How far the input is from settling, without updating it
+	pub fn progress(&self) -> Progress {
+		self.debounced.progress()
+	}
+
+	/// Updates the input, flipping the state if it was activated. Returns if
+	/// the input has settled.
+	fn update(&self) -> bool {
+		let last = self.debounced.last_settled();
+		let status = self.debounced.try_get();
+		let elapsed = self.elapsed.get();
+		if status == Some(A::ACTIVE_VALUE) && last != A::ACTIVE_VALUE && elapsed >= self.interval {
+			self.state.set(!self.state.get());
+			self.elapsed.set(0);
+		} else {
+			self.elapsed.set(elapsed.saturating_add(1));
+		}
+		status.is_some()
+	}
+
+	/// If the input has not settled, will not report the state.
+	///
+	/// If you just want to update the state (eg. in a timer interrupt), use
+	/// this function.
+	pub fn try_is_triggered(&self) -> Option<bool> {
+		if self.update() {
+			Some(self.is_set())
+		} else {
+			None
+		}
+	}
+
+	/// Updates the input, and reports the state even if the input has not
+	/// settled.
+	pub fn is_triggered_latest(&self) -> bool {
+		self.update();
+		self.is_set()
+	}
+
+	/// Blocks until the input has settled using tight polling, then reports
+	/// the state.
+	pub fn is_triggered_blocking(&self) -> bool {
+		while !self.update() {}
+		self.is_set()
+	}
+
+	/// Updates the input, and reports the state, or cleared (`false`) if the
+	/// input has not settled.
+	pub fn is_triggered_or_unset(&self) -> bool {
+		self.update() && self.is_set()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, DebouncedIntegrator};
+	use core::num::NonZeroU8;
+
+	#[test]
+	fn flips_on_press() {
+		let bit = Cell::new(false);
+		let t = Toggle::new(DebouncedIntegrator::<Low, _>::with_integrator(
+			NonZeroU8::new(2).unwrap(),
+			|| !bit.get(),
+		));
+		assert_eq!(t.try_is_triggered(), Some(false));
+		bit.set(true);
+		assert_eq!(t.try_is_triggered(), None);
+		assert_eq!(t.try_is_triggered(), Some(true));
+		// holding or bouncing does not flip again
+		assert!(t.is_triggered_latest());
+		bit.set(false);
+		assert!(t.is_triggered_latest());
+		bit.set(true);
+		assert!(t.is_triggered_latest());
+		// releasing does not flip
+		bit.set(false);
+		assert!(t.is_triggered_blocking());
+		bit.set(true);
+		assert!(!t.is_triggered_blocking());
+	}
+
+	#[test]
+	fn set_and_clear() {
+		let bit = Cell::new(false);
+		let t = Toggle::new(DebouncedIntegrator::<Low, _>::with_integrator(
+			NonZeroU8::new(2).unwrap(),
+			|| !bit.get(),
+		));
+		t.set();
+		assert!(t.is_triggered_latest());
+		t.clear();
+		assert!(!t.is_set());
+		bit.set(true);
+		assert!(t.is_triggered_blocking());
+	}
+
+	#[test]
+	fn unset_and_progress() {
+		let bit = Cell::new(true);
+		let t = Toggle::new(DebouncedIntegrator::<Low, _>::with_integrator(
+			NonZeroU8::new(2).unwrap(),
+			|| !bit.get(),
+		));
+		assert!(!t.is_triggered_or_unset());
+		assert_eq!(t.last_settled(), Status::High);
+		assert_eq!(t.progress(), Progress::new(Status::Low, 1, 2));
+		assert!(t.is_triggered_or_unset());
+		assert_eq!(t.last_settled(), Status::Low);
+	}
+
+	#[test]
+	fn interval() {
+		let bit = Cell::new(false);
+		let t = Toggle::with_interval(
+			DebouncedIntegrator::<Low, _>::with_integrator(NonZeroU8::new(2).unwrap(), || {
+				!bit.get()
+			}),
+			4,
+		);
+		bit.set(true);
+		assert!(t.is_triggered_blocking());
+		bit.set(false);
+		assert!(t.is_triggered_blocking());
+		bit.set(true);
+		assert!(t.is_triggered_blocking());
+		bit.set(false);
+		assert!(t.is_triggered_blocking());
+		bit.set(true);
+		assert!(!t.is_triggered_blocking());
+	}
+}
@@ -0,0 +1,332 @@
+//! # Tuning Debouncing Parameters
+//! Use recorded [`Trace`]s of a raw input, along with the times at which the
+//! input truly changed, to choose the parameters of a [`Strategy`].
+//!
+//! [`recommend`] tries each parameter in turn and returns the first one for
+//! which the strategy rejects every bounce in every trace. Give the
+//! parameters in increasing order of latency (eg. integrator distances from
+//! smallest to largest, or `(press, release)` pairs for asymmetric
+//! thresholds) to get the minimum setting. Use [`samples`](fn@crate::samples)
+//! to search over hold times at a given sample rate and
+//! [`hold_time_ms`](fn@crate::hold_time_ms) to convert results back.
+//!
+//! [`bounce_histogram`] summarizes how long the input bounced after each true
+//! transition.
+
+use crate::{strategy::Strategy, Status};
+
+/// # A Transition of an Input
+/// In a [`Trace`], a true transition, at the first sample after the input
+/// truly changed. From [`Edges`](crate::Edges), a debounced transition, at the
+/// sample which settled the strategy on a different value.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Edge {
+	/// Index of the sample at which the input changed
+	pub index: usize,
+	/// The value the input changed to
+	pub status: Status,
+}
+
+/// # A Recorded Raw Input
+/// The `samples` as they were read from the input and the ground-truth
+/// `edges`, both in order.
+///
+/// The strategies under test should start settled on the value the input has
+/// before its first edge.
+#[derive(Debug, Copy, Clone)]
+pub struct Trace<'a> {
+	/// The raw samples
+	pub samples: &'a [bool],
+	/// The true transitions of the input
+	pub edges: &'a [Edge],
+}
+
+impl Trace<'_> {
+	/// The true value of the input before its first edge
+	pub fn initial(&self) -> Status {
+		match (self.edges.first(), self.samples.first()) {
+			(Some(e), _) => !e.status,
+			(None, Some(&s)) => s.into(),
+			(None, None) => Status::Low,
+		}
+	}
+}
+
+/// # Detection Latency
+/// The number of samples between a true [`Edge`] and the strategy settling on
+/// the new value.
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Latency {
+	/// The smallest latency
+	pub min: usize,
+	/// The largest latency
+	pub max: usize,
+	/// The sum of all latencies
+	pub total: usize,
+	/// The number of detected edges
+	pub count: usize,
+}
+
+impl Latency {
+	fn record(&mut self, latency: usize) {
+		if self.count == 0 || latency < self.min {
+			self.min = latency;
+		}
+		if latency > self.max {
+			self.max = latency;
+		}
+		self.total += latency;
+		self.count += 1;
+	}
+
+	/// The average latency, rounded down. `0` if there were no edges.
+	pub fn mean(&self) -> usize {
+		self.total.checked_div(self.count).unwrap_or(0)
+	}
+
+	/// Combines the latencies of two evaluations
+	pub fn merge(self, other: Self) -> Self {
+		match (self.count, other.count) {
+			(0, _) => other,
+			(_, 0) => self,
+			_ => Self {
+				min: self.min.min(other.min),
+				max: self.max.max(other.max),
+				total: self.total + other.total,
+				count: self.count + other.count,
+			},
+		}
+	}
+}
+
+/// # Result of Running a [`Strategy`] on a [`Trace`]
+#[derive(Debug, PartialEq, Copy, Clone, Default)]
+pub struct Evaluation {
+	/// Changes of the settled value which do not correspond to a true edge
+	pub spurious: usize,
+	/// True edges which were never settled on before the next edge
+	pub missed: usize,
+	/// Latency of the detected edges
+	pub latency: Latency,
+}
+
+impl Evaluation {
+	/// Every edge was detected and no bounce was let through
+	pub fn passed(&self) -> bool {
+		self.spurious == 0 && self.missed == 0
+	}
+
+	/// Combines two evaluations (eg. of different traces)
+	pub fn merge(self, other: Self) -> Self {
+		Self {
+			spurious: self.spurious + other.spurious,
+			missed: self.missed + other.missed,
+			latency: self.latency.merge(other.latency),
+		}
+	}
+}
+
+/// Runs the `strategy` over the `trace`, comparing each change of its settled
+/// value to the true edges.
+pub fn evaluate<S: Strategy>(strategy: &S, trace: &Trace) -> Evaluation {
+	let mut eval = Evaluation::default();
+	let mut settled = strategy.status().unwrap_or_else(|| trace.initial());
+	let mut edges = trace.edges.iter().peekable();
+	let mut pending: Option<&Edge> = None;
+
+	for (i, &sample) in trace.samples.iter().enumerate() {
+		while let Some(edge) = edges.next_if(|e| e.index <= i) {
+			if pending.replace(edge).is_some() {
+				eval.missed += 1;
+			}
+		}
+		match strategy.update(sample.into()) {
+			Some(s) if s != settled => {
+				settled = s;
+				match pending {
+					Some(edge) if edge.status == s => {
+						eval.latency.record(i - edge.index);
+						pending = None;
+					}
+					_ => eval.spurious += 1,
+				}
+			}
+			_ => {}
+		}
+	}
+	eval.missed += pending.iter().count() + edges.count();
+	eval
+}
+
+/// # The Chosen Parameter
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Recommendation<P> {
+	/// The first parameter which passed every trace
+	pub param: P,
+	/// The detection latency over all traces using `param`
+	pub latency: Latency,
+}
+
+/// Finds the first of the `params` for which the strategy built by `make`
+/// detects every edge in every trace without letting any bounce through.
+///
+/// A new strategy is made for each trace. Returns `None` if no parameter
+/// passes.
+pub fn recommend<P, S, I, M>(traces: &[Trace], params: I, mut make: M) -> Option<Recommendation<P>>
+where
+	P: Copy,
+	S: Strategy,
+	I: IntoIterator<Item = P>,
+	M: FnMut(P) -> S,
+{
+	params.into_iter().find_map(|param| {
+		let eval = traces
+			.iter()
+			.map(|t| evaluate(&make(param), t))
+			.fold(Evaluation::default(), Evaluation::merge);
+		if eval.passed() {
+			Some(Recommendation {
+				param,
+				latency: eval.latency,
+			})
+		} else {
+			None
+		}
+	})
+}
+
+/// # Bounce Durations
+/// Counts of bounce durations (in samples), grouped into `B` buckets of
+/// `width` samples each. The last bucket also holds every longer bounce.
+#[derive(Debug, PartialEq, Copy, Clone)]
+pub struct Histogram<const B: usize> {
+	/// The number of samples covered by each bucket
+	pub width: usize,
+	/// The number of bounces in each bucket
+	pub buckets: [usize; B],
+	/// The longest bounce
+	pub longest: usize,
+}
+
+impl<const B: usize> Histogram<B> {
+	/// Create an empty histogram
+	///
+	/// A `width` of `0` is treated as `1`.
+	pub fn new(width: usize) -> Self {
+		Self {
+			width: width.max(1),
+			buckets: [0; B],
+			longest: 0,
+		}
+	}
+
+	/// Counts a bounce lasting `duration` samples
+	pub fn record(&mut self, duration: usize) {
+		if let Some(last) = B.checked_sub(1) {
+			self.buckets[(duration / self.width).min(last)] += 1;
+		}
+		self.longest = self.longest.max(duration);
+	}
+}
+
+/// Measures how long the input bounced after each true edge of the traces.
+///
+/// A bounce lasts from the edge until the input last reached the new value
+/// before the next edge (or the end of the trace).
+pub fn bounce_histogram<const B: usize>(traces: &[Trace], width: usize) -> Histogram<B> {
+	let mut hist = Histogram::new(width);
+	for trace in traces {
+		for (n, edge) in trace.edges.iter().enumerate() {
+			let end = trace
+				.edges
+				.get(n + 1)
+				.map_or(trace.samples.len(), |e| e.index)
+				.min(trace.samples.len());
+			let start = edge.index.min(end);
+			let unsettled = trace.samples[start..end]
+				.iter()
+				.rposition(|&s| Status::from(s) != edge.status)
+				.map_or(0, |p| p + 1);
+			hist.record(unsettled);
+		}
+	}
+	hist
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{active::Low, strategy::Integrator};
+	use core::num::NonZeroU8;
+
+	const SAMPLES: [bool; 24] = [
+		true, true, true, true, false, true, false, false, true, false, false, false, false, false,
+		false, true, false, true, true, true, true, true, true, true,
+	];
+	const EDGES: [Edge; 2] = [
+		Edge {
+			index: 4,
+			status: Status::Low,
+		},
+		Edge {
+			index: 15,
+			status: Status::High,
+		},
+	];
+
+	#[test]
+	fn evaluates_bounces() {
+		let trace = Trace {
+			samples: &SAMPLES,
+			edges: &EDGES,
+		};
+		assert_eq!(trace.initial(), Status::High);
+
+		let eval = evaluate(&Integrator::new::<Low>(NonZeroU8::new(1).unwrap()), &trace);
+		assert!(!eval.passed());
+		assert_eq!(eval.spurious, 6);
+		assert_eq!(eval.missed, 0);
+
+		let eval = evaluate(&Integrator::new::<Low>(NonZeroU8::new(3).unwrap()), &trace);
+		assert!(eval.passed());
+		assert_eq!(eval.latency.min, 4);
+		assert_eq!(eval.latency.max, 6);
+		assert_eq!(eval.latency.mean(), 5);
+	}
+
+	#[test]
+	fn missed_edges() {
+		let trace = Trace {
+			samples: &SAMPLES[..8],
+			edges: &EDGES,
+		};
+		let eval = evaluate(&Integrator::new::<Low>(NonZeroU8::new(3).unwrap()), &trace);
+		assert_eq!(eval.missed, 2);
+		assert_eq!(eval.latency.count, 0);
+	}
+
+	#[test]
+	fn recommends_minimum() {
+		let traces = [Trace {
+			samples: &SAMPLES,
+			edges: &EDGES,
+		}];
+		let integrator = |distance| Integrator::new::<Low>(NonZeroU8::new(distance).unwrap());
+		let r = recommend(&traces, 1..=10, integrator).unwrap();
+		assert_eq!(r.param, 2);
+		assert_eq!(r.latency.max, 3);
+
+		assert_eq!(recommend(&traces, 9..=10, integrator), None);
+	}
+
+	#[test]
+	fn histogram() {
+		let traces = [Trace {
+			samples: &SAMPLES,
+			edges: &EDGES,
+		}];
+		let hist = bounce_histogram::<3>(&traces, 2);
+		assert_eq!(hist.buckets, [0, 1, 1]);
+		assert_eq!(hist.longest, 5);
+	}
+}
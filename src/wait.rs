@@ -0,0 +1,105 @@
+use crate::{active::Active, strategy::Strategy, Debounced, InputSource, Status};
+use embedded_hal_async::delay::DelayNs;
+
+/// # Waiting Asynchronously
+/// With the `async` feature, a task can wait for a Debounced input to change.
+/// The input is sampled, then the `delay` waits for `period_us` (in µs)
+/// before the next sample, until the `strategy` settles as expected. Any
+/// executor (and any [`DelayNs`]) can be used.
+impl<A, S, F> Debounced<A, S, F>
+where
+	A: Active,
+	S: Strategy,
+	F: InputSource,
+{
+	/// Waits until the `strategy` settles on a [`Status`] which satisfies
+	/// `settled`
+	async fn wait_for<D: DelayNs>(
+		&self,
+		delay: &mut D,
+		period_us: u32,
+		settled: impl Fn(Status) -> bool,
+	) -> Status {
+		loop {
+			match self.try_get() {
+				Some(s) if settled(s) => return s,
+				_ => delay.delay_us(period_us).await,
+			}
+		}
+	}
+
+	/// Waits until the input settles on a different value than it last
+	/// settled on, and returns it
+	pub async fn wait_for_edge<D: DelayNs>(&self, delay: &mut D, period_us: u32) -> Status {
+		let last = self.last_settled();
+		self.wait_for(delay, period_us, |s| s != last).await
+	}
+
+	/// Waits until the input settles on the [active](trait@Active) value.
+	/// Returns after one sample if it is still active.
+	pub async fn wait_for_activated<D: DelayNs>(&self, delay: &mut D, period_us: u32) {
+		self.wait_for(delay, period_us, |s| s == A::ACTIVE_VALUE)
+			.await;
+	}
+
+	/// Waits until the input settles on the [inactive](trait@Active) value.
+	/// Returns after one sample if it is still inactive.
+	pub async fn wait_for_released<D: DelayNs>(&self, delay: &mut D, period_us: u32) {
+		self.wait_for(delay, period_us, |s| s != A::ACTIVE_VALUE)
+			.await;
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	extern crate std;
+	use super::*;
+	use crate::active::Low;
+	use core::{cell::Cell, future::Future, num::NonZeroU8, pin::pin, task::Context};
+	use std::{
+		sync::Arc,
+		task::{Poll, Wake, Waker},
+	};
+
+	struct NoopWaker;
+
+	impl Wake for NoopWaker {
+		fn wake(self: Arc<Self>) {}
+	}
+
+	fn block_on<T>(future: impl Future<Output = T>) -> T {
+		let waker = Waker::from(Arc::new(NoopWaker));
+		let mut cx = Context::from_waker(&waker);
+		let mut future = pin!(future);
+		loop {
+			if let Poll::Ready(t) = future.as_mut().poll(&mut cx) {
+				return t;
+			}
+		}
+	}
+
+	/// A delay which only advances a clock
+	struct FakeDelay<'a>(&'a Cell<u64>);
+
+	impl DelayNs for FakeDelay<'_> {
+		async fn delay_ns(&mut self, ns: u32) {
+			self.0.set(self.0.get() + u64::from(ns));
+		}
+	}
+
+	#[test]
+	fn waits() {
+		let now = Cell::new(0);
+		// pressed (low) from 1 ms to 5 ms, bouncing at 3 ms
+		let input = || !(1_000_000..5_000_000).contains(&now.get()) || now.get() == 3_000_000;
+		let d = Debounced::<Low, _, _>::with_integrator(NonZeroU8::new(3).unwrap(), input);
+		let mut delay = FakeDelay(&now);
+
+		block_on(d.wait_for_released(&mut delay, 500));
+		assert_eq!(now.get(), 0);
+		block_on(d.wait_for_activated(&mut delay, 500));
+		assert_eq!(now.get(), 2_000_000);
+		assert_eq!(block_on(d.wait_for_edge(&mut delay, 500)), Status::High);
+		assert_eq!(now.get(), 6_000_000);
+	}
+}